@@ -18,6 +18,7 @@ extern crate cheetah;
 
 use cheetah::AffinePoint;
 use cheetah::BasePointTable;
+use cheetah::Fp6;
 use cheetah::Scalar;
 
 static BATCH_SIZES: [usize; 4] = [1, 10, 100, 1000];
@@ -43,6 +44,8 @@ fn criterion_benchmark(c: &mut Criterion) {
 
     let uncompressed_encoding = p.to_uncompressed();
     let compressed_encoding = p.to_compressed();
+    let elligator_squared_encoding = p.to_elligator_squared_vartime(&mut rng);
+    let p_x = Fp6::from_bytes(&compressed_encoding.0[0..48].try_into().unwrap()).unwrap();
 
     c.bench_function("Affine scalar multiplication (variable base)", |bench| {
         bench.iter(|| AffinePoint::multiply(black_box(&p), black_box(&pow)))
@@ -96,9 +99,12 @@ fn criterion_benchmark(c: &mut Criterion) {
 
     let ct_batch_str = "Affine multi scalar multiplication - ".to_string();
     let vt_batch_str = "Affine multi scalar multiplication (variable time) - ".to_string();
+    let pippenger_batch_str =
+        "Affine multi scalar multiplication (Pippenger, variable time) - ".to_string();
     for &batch_size in BATCH_SIZES.iter() {
         let ct_name = ct_batch_str.clone() + &batch_size.to_string();
         let vt_name = vt_batch_str.clone() + &batch_size.to_string();
+        let pippenger_name = pippenger_batch_str.clone() + &batch_size.to_string();
         let affine_points = vec![AffinePoint::random(&mut rng); batch_size];
         let scalars = vec![Scalar::random(&mut rng).to_bytes(); batch_size];
         c.bench_function(&ct_name, |bench| {
@@ -110,6 +116,25 @@ fn criterion_benchmark(c: &mut Criterion) {
                 AffinePoint::multiply_many_vartime(black_box(&affine_points), black_box(&scalars))
             })
         });
+        c.bench_function(&pippenger_name, |bench| {
+            bench.iter(|| {
+                AffinePoint::multiply_many_pippenger_vartime(
+                    black_box(&affine_points),
+                    black_box(&scalars),
+                )
+            })
+        });
+    }
+
+    let sum_batch_str = "Affine batch sum - ".to_string();
+    for &batch_size in BATCH_SIZES.iter() {
+        let sum_name = sum_batch_str.clone() + &batch_size.to_string();
+        let affine_points: Vec<AffinePoint> = (0..batch_size)
+            .map(|_| AffinePoint::random(&mut rng))
+            .collect();
+        c.bench_function(&sum_name, |bench| {
+            bench.iter(|| AffinePoint::batch_sum(black_box(&affine_points)))
+        });
     }
 
     c.bench_function("Affine basepoint table creation", |bench| {
@@ -132,6 +157,20 @@ fn criterion_benchmark(c: &mut Criterion) {
         bench.iter(|| AffinePoint::from_compressed(black_box(&compressed_encoding)))
     });
 
+    c.bench_function("Affine x-only scalar multiplication", |bench| {
+        bench.iter(|| AffinePoint::multiply_x_only(black_box(&p_x), black_box(&pow)))
+    });
+
+    c.bench_function("Affine Elligator Squared encoding", |bench| {
+        bench.iter(|| AffinePoint::to_elligator_squared_vartime(black_box(&p), &mut rng))
+    });
+
+    c.bench_function("Affine Elligator Squared decoding", |bench| {
+        bench.iter(|| {
+            AffinePoint::from_elligator_squared_vartime(black_box(&elligator_squared_encoding))
+        })
+    });
+
     c.bench_function("Affine curve check", |bench| {
         bench.iter(|| AffinePoint::is_on_curve(black_box(&p)))
     });
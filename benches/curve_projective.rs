@@ -19,7 +19,7 @@ extern crate cheetah;
 use cheetah::Scalar;
 use cheetah::{AffinePoint, ProjectivePoint};
 
-use cheetah::{BasePointTable, BASEPOINT_TABLE};
+use cheetah::{BasePointTable, FixedBaseMsmTable, BASEPOINT_TABLE};
 
 static BATCH_SIZES: [usize; 4] = [1, 10, 100, 1000];
 
@@ -136,9 +136,12 @@ fn criterion_benchmark(c: &mut Criterion) {
 
     let ct_batch_str = "Projective multi scalar multiplication - ".to_string();
     let vt_batch_str = "Projective multi scalar multiplication (variable time) - ".to_string();
+    let pippenger_batch_str =
+        "Projective multi scalar multiplication (Pippenger, variable time) - ".to_string();
     for &batch_size in BATCH_SIZES.iter() {
         let ct_name = ct_batch_str.clone() + &batch_size.to_string();
         let vt_name = vt_batch_str.clone() + &batch_size.to_string();
+        let pippenger_name = pippenger_batch_str.clone() + &batch_size.to_string();
         let projective_points = vec![ProjectivePoint::random(&mut rng); batch_size];
         let scalars = vec![Scalar::random(&mut rng).to_bytes(); batch_size];
         c.bench_function(&ct_name, |bench| {
@@ -154,6 +157,57 @@ fn criterion_benchmark(c: &mut Criterion) {
                 )
             })
         });
+        c.bench_function(&pippenger_name, |bench| {
+            bench.iter(|| {
+                ProjectivePoint::multiply_many_pippenger_vartime(
+                    black_box(&projective_points),
+                    black_box(&scalars),
+                )
+            })
+        });
+    }
+
+    let with_tables_batch_str =
+        "Projective multi scalar multiplication with basepoint table (variable time) - "
+            .to_string();
+    for &batch_size in BATCH_SIZES.iter() {
+        let name = with_tables_batch_str.clone() + &batch_size.to_string();
+        let projective_points = vec![ProjectivePoint::random(&mut rng); batch_size];
+        let scalars = vec![Scalar::random(&mut rng).to_bytes(); batch_size];
+        c.bench_function(&name, |bench| {
+            bench.iter(|| {
+                ProjectivePoint::multiply_many_with_tables_vartime::<8>(
+                    black_box(&[&BASEPOINT_TABLE]),
+                    black_box(&[pow]),
+                    &[],
+                    &[],
+                    black_box(&projective_points),
+                    black_box(&scalars),
+                )
+            })
+        });
+    }
+
+    let fixed_base_ct_batch_str =
+        "Projective fixed-base multi scalar multiplication - ".to_string();
+    let fixed_base_vt_batch_str =
+        "Projective fixed-base multi scalar multiplication (variable time) - ".to_string();
+    for &batch_size in BATCH_SIZES.iter() {
+        let ct_name = fixed_base_ct_batch_str.clone() + &batch_size.to_string();
+        let vt_name = fixed_base_vt_batch_str.clone() + &batch_size.to_string();
+        let points: Vec<AffinePoint> = (0..batch_size)
+            .map(|_| AffinePoint::random(&mut rng))
+            .collect();
+        let table = FixedBaseMsmTable::create(&points);
+        let scalars = vec![Scalar::random(&mut rng).to_bytes(); batch_size];
+        c.bench_function(&ct_name, |bench| {
+            bench.iter(|| FixedBaseMsmTable::multiply(black_box(&table), black_box(&scalars)))
+        });
+        c.bench_function(&vt_name, |bench| {
+            bench.iter(|| {
+                FixedBaseMsmTable::multiply_vartime(black_box(&table), black_box(&scalars))
+            })
+        });
     }
 
     c.bench_function("Projective basepoint table creation", |bench| {
@@ -184,10 +238,45 @@ fn criterion_benchmark(c: &mut Criterion) {
         bench.iter(|| ProjectivePoint::is_torsion_free(black_box(&p)))
     });
 
+    c.bench_function("Projective subgroup check (vartime)", |bench| {
+        bench.iter(|| ProjectivePoint::is_torsion_free_vartime(black_box(&p)))
+    });
+
+    let batch: Vec<ProjectivePoint> = (0..1000)
+        .map(|_| ProjectivePoint::random(&mut rng))
+        .collect();
+
+    c.bench_function(
+        "Projective batch subgroup check - 1000 points, 128 bits",
+        |bench| {
+            bench.iter(|| {
+                ProjectivePoint::batch_is_torsion_free_vartime(black_box(&batch), 128, &mut rng)
+            })
+        },
+    );
+
     c.bench_function("Projective points equality", |bench| {
         bench.iter(|| black_box(p) == black_box(p))
     });
 
+    c.bench_function("Projective hash to curve", |bench| {
+        bench.iter(|| {
+            ProjectivePoint::hash_to_curve(
+                black_box(b"cheetah"),
+                black_box(b"CHEETAH-BENCH-V01-CS01-with-CHEETAH_XMD:SHA-256_SSWU_RO_"),
+            )
+        })
+    });
+
+    c.bench_function("Projective encode to curve", |bench| {
+        bench.iter(|| {
+            ProjectivePoint::encode_to_curve(
+                black_box(b"cheetah"),
+                black_box(b"CHEETAH-BENCH-V01-CS01-with-CHEETAH_XMD:SHA-256_SSWU_NU_"),
+            )
+        })
+    });
+
     c.bench_function("Projective conversion to affine", |bench| {
         bench.iter(|| AffinePoint::from(black_box(&p)))
     });
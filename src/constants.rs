@@ -6,11 +6,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::recoding;
+use crate::Scalar;
 use crate::{
     AffinePoint, BasePointTable, JacobianPoint, LookupTable, ModifiedJacobianPoint, NafLookupTable,
     ProjectivePoint,
 };
-use crate::{Fp, Fp6, Scalar};
 
 use core::ops::Mul;
 use subtle::Choice;
@@ -32,6 +33,8 @@ lazy_static! {
     pub static ref ODD_MULTIPLES_BASEPOINT: NafLookupTable::<64> =
         NafLookupTable::<64>::from(&AffinePoint::generator());
 
+    // The coordinates are compile-time literals, but an `AffinePoint` holds a
+    // `subtle::Choice`, which cannot be built in a const context.
     /// A fixed shift point of the curve in affine coordinates
     /// The point has been generated from the Simplified Shallue-van
     /// de Woestijne-Ulas method for hashing to elliptic curves in
@@ -41,22 +44,22 @@ lazy_static! {
     /// See https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve
     /// for more.
     pub static ref SHIFT_POINT_AFFINE: AffinePoint = AffinePoint {
-        x: Fp6 {
-            c0: Fp(0xab95fb0d188ff2b1),
-            c1: Fp(0xf38263a8c954493e),
-            c2: Fp(0x1bc968d6739028b7),
-            c3: Fp(0x4f67684fe6b27d1f),
-            c4: Fp(0x6504f8bbba9077b4),
-            c5: Fp(0x860838116d76e545),
-        },
-        y: Fp6 {
-            c0: Fp(0x56988918d5b02cf3),
-            c1: Fp(0xc9fe3ef9d7652c54),
-            c2: Fp(0x4923e90c4d6f4538),
-            c3: Fp(0xc747ac3f82369f27),
-            c4: Fp(0x6e74c106972c71f5),
-            c5: Fp(0xcf190938f1c64bb5),
-        },
+        x: fp6!(
+            12364064385680798385,
+            17546696674772273470,
+            2002246779614013623,
+            5721656543979666719,
+            7279216382910166964,
+            9658031048398136645,
+        ),
+        y: fp6!(
+            6239888023479135475,
+            14555140288489532500,
+            5270312228001629496,
+            14359635325661650727,
+            7959198675520745973,
+            14922968980447972277,
+        ),
         infinity: Choice::from(0u8),
     };
 
@@ -103,7 +106,7 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a BASEPOINT_LOOKUP {
     type Output = JacobianPoint;
 
     fn mul(self, scalar: &'b Scalar) -> Self::Output {
-        let digits = Scalar::bytes_to_radix_256(&scalar.to_bytes());
+        let digits = recoding::to_signed_window_256(&scalar.to_bytes());
 
         let mut acc = *SHIFT_POINT_MODIFIED_JACOBIAN;
         for i in (0..32).rev() {
@@ -122,7 +125,7 @@ impl BASEPOINT_LOOKUP {
     /// Performs a mixed scalar multiplication from `by`
     /// given as byte representation of a `Scalar` element
     pub fn multiply(&self, by: &[u8; 32]) -> JacobianPoint {
-        let digits = Scalar::bytes_to_radix_256(by);
+        let digits = recoding::to_signed_window_256(by);
 
         let mut acc = *SHIFT_POINT_MODIFIED_JACOBIAN;
         for i in (0..32).rev() {
@@ -140,7 +143,7 @@ impl BASEPOINT_LOOKUP {
     /// to the scalar.** If the scalar is fixed,
     /// this operation is effectively constant time.
     pub fn multiply_vartime(&self, by: &[u8; 32]) -> JacobianPoint {
-        let digits = Scalar::bytes_to_radix_256(by);
+        let digits = recoding::to_signed_window_256(by);
 
         let mut acc = *SHIFT_POINT_MODIFIED_JACOBIAN;
         for i in (0..32).rev() {
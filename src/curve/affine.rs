@@ -13,18 +13,25 @@
 //! internally rely on modified jacobian coordinates arithmetic.
 
 use core::{
+    borrow::Borrow,
     fmt,
     hash::{Hash, Hasher},
+    iter::Sum,
     ops::{Add, Mul, Neg, Sub},
 };
 
+use super::pippenger;
+use super::torsion::is_torsion_free_with_table;
 use super::B;
 
-use crate::{CompressedPoint, UncompressedPoint};
-use crate::{Fp, Fp6, Scalar};
+use crate::{BasePointTable, NafLookupTable};
+use crate::{CompressedPoint, ElligatorSquaredPoint, UncompressedPoint};
+use crate::{Fp6, Scalar};
 use crate::{JacobianPoint, ModifiedJacobianPoint, ProjectivePoint};
 
+use alloc::vec;
 use alloc::vec::Vec;
+use group::{GroupEncoding, UncompressedEncoding};
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
@@ -253,22 +260,22 @@ impl AffinePoint {
     /// for more.
     pub fn generator() -> AffinePoint {
         AffinePoint {
-            x: Fp6 {
-                c0: Fp(0x263a588f4b0118a1),
-                c1: Fp(0x7757a0bcb26a142d),
-                c2: Fp(0x9215adfc1e925890),
-                c3: Fp(0x430aad2ce14759a4),
-                c4: Fp(0x534ece54de4b2c8),
-                c5: Fp(0xb39050f01f7b1f33),
-            },
-            y: Fp6 {
-                c0: Fp(0xd57f0d0d47482534),
-                c1: Fp(0x26821d894fa8ea0f),
-                c2: Fp(0xc77f564783ef13a1),
-                c3: Fp(0x949c360784284ec2),
-                c4: Fp(0xb7040bd639ef3cc4),
-                c5: Fp(0x8aa635f2719d255f),
-            },
+            x: fp6!(
+                0x263a588f4b0118a1,
+                0x7757a0bcb26a142d,
+                0x9215adfc1e925890,
+                0x430aad2ce14759a4,
+                0x534ece54de4b2c8,
+                0xb39050f01f7b1f33,
+            ),
+            y: fp6!(
+                0xd57f0d0d47482534,
+                0x26821d894fa8ea0f,
+                0xc77f564783ef13a1,
+                0x949c360784284ec2,
+                0xb7040bd639ef3cc4,
+                0x8aa635f2719d255f,
+            ),
             infinity: Choice::from(0u8),
         }
     }
@@ -315,6 +322,23 @@ impl AffinePoint {
         compressed_point.to_affine()
     }
 
+    /// Outputs a random Elligator Squared encoding of the prime-order component
+    /// of this `AffinePoint` element, indistinguishable from a uniformly random
+    /// byte sequence when this point is uniformly random in the subgroup.
+    ///
+    /// **This operation is variable time with respect to the point.**
+    pub fn to_elligator_squared_vartime(&self, rng: impl RngCore) -> ElligatorSquaredPoint {
+        ElligatorSquaredPoint::from_affine_vartime(self, rng)
+    }
+
+    /// Deserializes an Elligator Squared encoding. Any encoding
+    /// decodes to a point of the prime-order subgroup.
+    ///
+    /// **This operation is variable time with respect to the encoding.**
+    pub fn from_elligator_squared_vartime(encoding: &ElligatorSquaredPoint) -> Self {
+        encoding.to_affine_vartime()
+    }
+
     /// Attempts to recover an AffinePoint from an `x` coordinate. The internal behavior is similar
     /// to calling `AffinePoint::from_compressed()` on a byte sequence. This method will always
     /// choose the lexicographically largest `y` coordinate corresponding to the provided `x` one.
@@ -428,6 +452,38 @@ impl AffinePoint {
             .into()
     }
 
+    /// Performs the affine multiscalar multiplication ∑ a[i].T[i] + ∑ b[i].N[i] + ∑ s[i].p[i],
+    /// where the T[i] are the basepoints of the given `BasePointTable`s, the N[i] are
+    /// the basepoints of the given `NafLookupTable`s, and the p[i] are arbitrary points,
+    /// with all the scalars given as byte representations of `Scalar` elements.
+    ///
+    /// **This operation is variable time with respect
+    /// to the scalars.** If the scalars are fixed,
+    /// this operation is effectively constant time.
+    ///
+    /// # Panics
+    /// Panics if any list of points has a different length than its associated
+    /// list of scalars, or if `N` is not a power of two smaller than 64.
+    pub fn multiply_many_with_tables_vartime<const N: usize>(
+        basepoint_tables: &[&BasePointTable],
+        basepoint_table_scalars: &[[u8; 32]],
+        naf_tables: &[&NafLookupTable<N>],
+        naf_table_scalars: &[[u8; 32]],
+        points: &[AffinePoint],
+        scalars: &[[u8; 32]],
+    ) -> AffinePoint {
+        let points_jacobian: Vec<JacobianPoint> = points.iter().map(|p| p.into()).collect();
+        JacobianPoint::multiply_many_with_tables_vartime(
+            basepoint_tables,
+            basepoint_table_scalars,
+            naf_tables,
+            naf_table_scalars,
+            &points_jacobian,
+            scalars,
+        )
+        .into()
+    }
+
     /// Performs the affine multiscalar multiplication ∑ s[i].p[i] with
     /// the s[i] given as byte representations of `Scalar` elements.
     pub fn multiply_many(points: &[AffinePoint], scalars: &[[u8; 32]]) -> AffinePoint {
@@ -450,6 +506,27 @@ impl AffinePoint {
         ProjectivePoint::multiply_many_vartime(&points_projective, scalars).into()
     }
 
+    /// Performs the affine multiscalar multiplication ∑ s[i].p[i] with
+    /// the s[i] given as byte representations of `Scalar` elements, using
+    /// Pippenger's bucket method with a window width depending on the number
+    /// of points. This scales better than `multiply_many_vartime` for large
+    /// batches of points.
+    ///
+    /// **This operation is variable time with respect
+    /// to the scalars.** If the scalars are fixed,
+    /// this operation is effectively constant time.
+    pub fn multiply_many_pippenger_vartime(
+        points: &[AffinePoint],
+        scalars: &[[u8; 32]],
+    ) -> AffinePoint {
+        pippenger::multiply_many_vartime(
+            points,
+            scalars,
+            pippenger::optimal_window_width(points.len()),
+        )
+        .into()
+    }
+
     /// Multiplies by the curve cofactor
     pub fn clear_cofactor(&self) -> AffinePoint {
         let point: JacobianPoint = self.into();
@@ -460,9 +537,116 @@ impl AffinePoint {
     /// Returns true if this point is free of an $h$-torsion component.
     /// This should always return true unless an "unchecked" API was used.
     pub fn is_torsion_free(&self) -> Choice {
-        let point: JacobianPoint = self.into();
+        // Multiply the point by q and check if it is the identity
+        is_torsion_free_with_table(&NafLookupTable::<8>::from(self))
+    }
 
-        point.is_torsion_free()
+    /// Returns true if this point is free of an $h$-torsion component,
+    /// by multiplying it by q with a generic scalar multiplication.
+    /// This is slower than `is_torsion_free`, and mostly serves as a
+    /// reference for it.
+    ///
+    /// **This operation is variable time with respect to the point.**
+    pub fn is_torsion_free_vartime(&self) -> Choice {
+        JacobianPoint::from(self).is_torsion_free_vartime()
+    }
+
+    /// Computes the sums `lhs[i] + rhs[i]` of n pairs of points into `output`,
+    /// sharing a single field inversion among all additions with Montgomery's
+    /// trick.
+    ///
+    /// Panics if `lhs`, `rhs` and `output` do not have the same length.
+    pub fn batch_add(lhs: &[AffinePoint], rhs: &[AffinePoint], output: &mut [AffinePoint]) {
+        assert_eq!(lhs.len(), rhs.len(), "inputs must have the same length");
+        assert_eq!(
+            lhs.len(),
+            output.len(),
+            "output must have the same length as inputs"
+        );
+
+        // For each pair, the slope of the line going through both points is
+        // λ = (y2 - y1) / (x2 - x1) for an addition and λ = (3.x1^2 + 1) / 2.y1
+        // for a doubling. Pairs whose sum is trivially known are assigned a
+        // dummy denominator of 1, so that all denominators are invertible.
+        let mut numerators = Vec::with_capacity(lhs.len());
+        let mut denominators = Vec::with_capacity(lhs.len());
+        for (p, q) in lhs.iter().zip(rhs) {
+            let is_same_x = p.x.ct_eq(&q.x);
+            let is_trivial = p.is_identity() | q.is_identity() | (is_same_x & p.y.ct_eq(&-q.y));
+
+            let x_sq = p.x.square();
+            let numerator = Fp6::conditional_select(
+                &(q.y - p.y),
+                &(x_sq.double() + x_sq + Fp6::one()),
+                is_same_x,
+            );
+            let denominator = Fp6::conditional_select(&(q.x - p.x), &p.y.double(), is_same_x);
+
+            numerators.push(numerator);
+            denominators.push(Fp6::conditional_select(
+                &denominator,
+                &Fp6::one(),
+                is_trivial,
+            ));
+        }
+
+        // Montgomery's trick: we use `output` x coordinates to store
+        // the product of previous denominators seen.
+        let mut acc = Fp6::one();
+        for (out, denominator) in output.iter_mut().zip(&denominators) {
+            out.x = acc;
+            acc *= denominator;
+        }
+
+        // This is the inverse, as all denominators are nonzero.
+        acc = acc.invert().unwrap();
+
+        for (i, out) in output.iter_mut().enumerate().rev() {
+            let (p, q) = (&lhs[i], &rhs[i]);
+
+            // Compute 1 / denominator and cancel it out of `acc`
+            let inverse = out.x * acc;
+            acc *= denominators[i];
+
+            let lambda = numerators[i] * inverse;
+            let x3 = lambda.square() - p.x - q.x;
+            let y3 = lambda * (p.x - x3) - p.y;
+
+            *out = AffinePoint {
+                x: x3,
+                y: y3,
+                infinity: Choice::from(0u8),
+            };
+
+            let is_opposite = p.x.ct_eq(&q.x) & p.y.ct_eq(&-q.y);
+            out.conditional_assign(&AffinePoint::identity(), is_opposite);
+            out.conditional_assign(p, q.is_identity());
+            out.conditional_assign(q, p.is_identity());
+        }
+    }
+
+    /// Computes the sum of all the given points, by recursively adding them
+    /// pairwise with `batch_add`, hence requiring a single field inversion per
+    /// level of the reduction tree.
+    pub fn batch_sum(points: &[AffinePoint]) -> AffinePoint {
+        if points.is_empty() {
+            return AffinePoint::identity();
+        }
+
+        let mut current = points.to_vec();
+        while current.len() > 1 {
+            let half = current.len() / 2;
+            let mut next = vec![AffinePoint::identity(); half];
+            AffinePoint::batch_add(&current[..half], &current[half..2 * half], &mut next);
+
+            // Carry over the remaining point, if any
+            if current.len() % 2 == 1 {
+                next.push(current[2 * half]);
+            }
+            current = next;
+        }
+
+        current[0]
     }
 }
 
@@ -476,6 +660,57 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a AffinePoint {
 
 impl_binops_multiplicative_mixed!(AffinePoint, Scalar, JacobianPoint);
 
+impl<T> Sum<T> for AffinePoint
+where
+    T: Borrow<AffinePoint>,
+{
+    /// Computes the sum of all points of the iterator with batched
+    /// affine additions. See `AffinePoint::batch_sum`.
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        let points: Vec<AffinePoint> = iter.map(|p| *p.borrow()).collect();
+
+        AffinePoint::batch_sum(&points)
+    }
+}
+
+// GROUP TRAITS IMPLEMENTATION
+// ================================================================================================
+
+impl GroupEncoding for AffinePoint {
+    type Repr = CompressedPoint;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_compressed(bytes)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_compressed_unchecked(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.to_compressed()
+    }
+}
+
+impl UncompressedEncoding for AffinePoint {
+    type Uncompressed = UncompressedPoint;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed_unchecked(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        self.to_uncompressed()
+    }
+}
+
 // SERDE SERIALIZATION
 // ================================================================================================
 
@@ -508,6 +743,7 @@ impl<'de> Deserialize<'de> for AffinePoint {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::curve::non_torsion_free_point;
     use rand_core::OsRng;
 
     #[test]
@@ -583,27 +819,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_affine_multiply_many_pippenger() {
+        let mut rng = OsRng;
+
+        for n in [0, 1, 2, 33, 100] {
+            let points: Vec<AffinePoint> = (0..n).map(|_| AffinePoint::random(&mut rng)).collect();
+            let mut scalars: Vec<[u8; 32]> = (0..n)
+                .map(|_| Scalar::random(&mut rng).to_bytes())
+                .collect();
+            if n > 1 {
+                scalars[0] = Scalar::zero().to_bytes();
+                scalars[1] = (-Scalar::one()).to_bytes();
+            }
+
+            assert_eq!(
+                AffinePoint::multiply_many_pippenger_vartime(&points, &scalars),
+                AffinePoint::multiply_many_vartime(&points, &scalars)
+            );
+        }
+    }
+
+    #[test]
+    fn test_affine_multiply_many_with_tables() {
+        let mut rng = OsRng;
+        let g = AffinePoint::random(&mut rng);
+        let h = AffinePoint::random(&mut rng);
+        let p = AffinePoint::random(&mut rng);
+        let g_table = BasePointTable::from(&g);
+        let h_table = NafLookupTable::<64>::from(&h);
+
+        for _ in 0..10 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let s = Scalar::random(&mut rng);
+
+            let expected = ProjectivePoint::from(g) * a
+                + ProjectivePoint::from(h) * b
+                + ProjectivePoint::from(p) * s;
+            assert_eq!(
+                AffinePoint::multiply_many_with_tables_vartime(
+                    &[&g_table],
+                    &[a.to_bytes()],
+                    &[&h_table],
+                    &[b.to_bytes()],
+                    &[p],
+                    &[s.to_bytes()],
+                ),
+                AffinePoint::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_add() {
+        let mut rng = OsRng;
+        let p = AffinePoint::random(&mut rng);
+        let q = AffinePoint::random(&mut rng);
+        let identity = AffinePoint::identity();
+
+        let mut lhs = vec![p, p, p, p, identity, identity, -p];
+        let mut rhs = vec![q, p, -p, identity, q, identity, -p];
+        for _ in 0..20 {
+            lhs.push(AffinePoint::random(&mut rng));
+            rhs.push(AffinePoint::random(&mut rng));
+        }
+
+        let mut output = vec![AffinePoint::identity(); lhs.len()];
+        AffinePoint::batch_add(&lhs, &rhs, &mut output);
+
+        for ((p, q), r) in lhs.iter().zip(&rhs).zip(&output) {
+            let expected = ProjectivePoint::from(p) + q;
+            assert_eq!(*r, AffinePoint::from(expected));
+            assert!(bool::from(r.is_on_curve()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_add_invalid_length() {
+        let mut output = [AffinePoint::identity(); 2];
+        AffinePoint::batch_add(
+            &[AffinePoint::generator(); 2],
+            &[AffinePoint::generator()],
+            &mut output,
+        );
+    }
+
+    #[test]
+    fn test_batch_sum() {
+        let mut rng = OsRng;
+
+        for n in [0, 1, 2, 3, 17, 64] {
+            let points: Vec<AffinePoint> = (0..n).map(|_| AffinePoint::random(&mut rng)).collect();
+            let expected: ProjectivePoint = points
+                .iter()
+                .fold(ProjectivePoint::identity(), |acc, p| acc + p);
+
+            assert_eq!(AffinePoint::batch_sum(&points), AffinePoint::from(expected));
+            assert_eq!(
+                points.iter().sum::<AffinePoint>(),
+                AffinePoint::from(expected)
+            );
+        }
+
+        // Sums going through intermediate doublings and cancellations
+        let p = AffinePoint::random(&mut rng);
+        assert_eq!(
+            AffinePoint::batch_sum(&[p, p, p, p]),
+            AffinePoint::from(ProjectivePoint::from(p).double().double())
+        );
+        assert_eq!(AffinePoint::batch_sum(&[p, -p, p, -p, p]), p);
+    }
+
     #[test]
     fn test_is_torsion_free() {
-        let a = AffinePoint {
-            x: Fp6 {
-                c0: Fp(0x9bfcd3244afcb637),
-                c1: Fp(0x39005e478830b187),
-                c2: Fp(0x7046f1c03b42c6cc),
-                c3: Fp(0xb5eeac99193711e5),
-                c4: Fp(0x7fd272e724307b98),
-                c5: Fp(0xcc371dd6dd5d8625),
-            },
-            y: Fp6 {
-                c0: Fp(0x9d03fdc216dfaae8),
-                c1: Fp(0xbf4ade2a7665d9b8),
-                c2: Fp(0xf08b022d5b3262b7),
-                c3: Fp(0x2eaf583a3cf15c6f),
-                c4: Fp(0xa92531e4b1338285),
-                c5: Fp(0x5b8157814141a7a7),
-            },
-            infinity: Choice::from(0u8),
-        };
+        let a = non_torsion_free_point();
         assert!(bool::from(a.is_on_curve()));
         assert!(!bool::from(a.is_torsion_free()));
         assert!(bool::from(AffinePoint::identity().is_torsion_free()));
@@ -749,6 +1080,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_point_elligator_squared() {
+        let mut rng = OsRng;
+
+        // Random points
+        for _ in 0..10 {
+            let point = AffinePoint::random(&mut rng);
+            let bytes = point.to_elligator_squared_vartime(&mut rng);
+            let point_decoded = AffinePoint::from_elligator_squared_vartime(&bytes);
+            assert_eq!(point, point_decoded);
+        }
+
+        // Identity point
+        {
+            let bytes = AffinePoint::identity().to_elligator_squared_vartime(&mut rng);
+            let point_decoded = AffinePoint::from_elligator_squared_vartime(&bytes);
+            assert!(bool::from(point_decoded.is_identity()));
+        }
+
+        // Arbitrary bytes decode to points of the prime-order subgroup
+        {
+            let bytes = ElligatorSquaredPoint([1u8; 192]);
+            let point_decoded = AffinePoint::from_elligator_squared_vartime(&bytes);
+            assert!(bool::from(point_decoded.is_on_curve()));
+            assert!(bool::from(point_decoded.is_torsion_free()));
+        }
+    }
+
+    #[test]
+    fn test_group_encoding() {
+        let mut rng = OsRng;
+        let mut points = vec![AffinePoint::identity(), AffinePoint::generator()];
+        for _ in 0..10 {
+            points.push(AffinePoint::random(&mut rng));
+        }
+
+        for p in points {
+            let bytes = GroupEncoding::to_bytes(&p);
+            assert_eq!(bytes, p.to_compressed());
+            assert_eq!(
+                <AffinePoint as GroupEncoding>::from_bytes(&bytes).unwrap(),
+                p
+            );
+            assert_eq!(
+                <AffinePoint as GroupEncoding>::from_bytes_unchecked(&bytes).unwrap(),
+                p
+            );
+
+            let bytes = UncompressedEncoding::to_uncompressed(&p);
+            assert_eq!(bytes, p.to_uncompressed());
+            assert_eq!(
+                <AffinePoint as UncompressedEncoding>::from_uncompressed(&bytes).unwrap(),
+                p
+            );
+            assert_eq!(
+                <AffinePoint as UncompressedEncoding>::from_uncompressed_unchecked(&bytes).unwrap(),
+                p
+            );
+        }
+
+        // Points outside of the prime-order subgroup are
+        // only accepted by the unchecked variants.
+        let a = non_torsion_free_point();
+
+        let bytes = GroupEncoding::to_bytes(&a);
+        assert!(bool::from(
+            <AffinePoint as GroupEncoding>::from_bytes(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            <AffinePoint as GroupEncoding>::from_bytes_unchecked(&bytes).is_some()
+        ));
+
+        let bytes = UncompressedEncoding::to_uncompressed(&a);
+        assert!(bool::from(
+            <AffinePoint as UncompressedEncoding>::from_uncompressed(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            <AffinePoint as UncompressedEncoding>::from_uncompressed_unchecked(&bytes).is_some()
+        ));
+    }
+
     // SERDE SERIALIZATIOIN
     // ================================================================================================
 
@@ -0,0 +1,468 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides an Elligator Squared encoding of cheetah points
+//! (https://eprint.iacr.org/2014/043.pdf), mapping curve points to byte
+//! strings indistinguishable from uniformly random ones.
+//!
+//! A point P of the prime-order subgroup is represented by two field
+//! elements (u0, u1) such that P + T = f(u0) + f(u1), where f is the
+//! Simplified SWU map used for hashing to the curve and T is a uniformly
+//! random $h$-torsion point. The pair (u0, u1) is sampled at random among
+//! all valid preimages of P + T.
+//!
+//! Without T, decoding a random byte string would yield a point outside of
+//! the prime-order subgroup with overwhelming probability, while encodings
+//! would always decode to torsion-free points. With T, the point P + T is
+//! uniform over the whole curve when P is uniform over the subgroup, so that
+//! the distribution of (u0, u1) is statistically close to uniform. Decoding
+//! then recovers P as the prime-order component of f(u0) + f(u1).
+
+use super::hash_to_curve::{map_to_curve_simple_swu, sgn0, Z};
+use super::B;
+
+use crate::fp::reduce_u128;
+use crate::{AffinePoint, Fp, Fp6, ProjectivePoint};
+use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "serialize")]
+use core::fmt;
+#[cfg(feature = "serialize")]
+use serde::de::Visitor;
+#[cfg(feature = "serialize")]
+use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+// Number of bytes used to represent a base field coordinate
+const COORDINATE_LENGTH: usize = 16;
+
+// Number of bytes used to represent an `Fp6` element
+const FIELD_ELEMENT_LENGTH: usize = 6 * COORDINATE_LENGTH;
+
+// Field modulus p, as a 128-bit integer
+const MODULUS: u128 = 0xffffffff00000001;
+
+/// An Elligator Squared encoding of a point, storing two `Fp6` elements
+/// whose images by the Simplified SWU map sum to the encoded point, up to
+/// a random $h$-torsion component.
+///
+/// Each base field coordinate is stored as a random 128-bit little-endian
+/// representative of its residue class modulo p, so that the encoding of
+/// a uniformly random point of the prime-order subgroup is within statistical
+/// distance 2^-60 of a uniformly random byte string. Conversely, any 192-byte
+/// string decodes to a point of the prime-order subgroup.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ElligatorSquaredPoint(pub [u8; 192]);
+
+impl Default for ElligatorSquaredPoint {
+    fn default() -> Self {
+        Self([0u8; 192])
+    }
+}
+
+impl AsRef<[u8]> for ElligatorSquaredPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for ElligatorSquaredPoint {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl ConstantTimeEq for ElligatorSquaredPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
+impl ElligatorSquaredPoint {
+    /// Samples a random Elligator Squared encoding of an `AffinePoint`.
+    /// Only the prime-order component of the point is encoded, as its
+    /// $h$-torsion component is replaced by a uniformly random one.
+    ///
+    /// **This operation is variable time with respect to the point.**
+    /// The number of sampling attempts depends on the point and on the
+    /// randomness provided, and is on average close to 4.
+    pub(crate) fn from_affine_vartime(point: &AffinePoint, mut rng: impl RngCore) -> Self {
+        let point = ProjectivePoint::from(point) + ProjectivePoint::random_torsion_point(&mut rng);
+
+        loop {
+            let u0 = Fp6::random(&mut rng);
+            let q = AffinePoint::from(point - map_to_curve_simple_swu(&u0));
+
+            // Selecting one of the four preimage slots uniformly, and restarting
+            // if it is empty, makes (u0, u1) close to uniform over Fp6 x Fp6.
+            let slot = (rng.next_u32() % 4) as usize;
+            if let Some(u1) = swu_preimage_vartime(&q, slot) {
+                let mut bytes = [0u8; 192];
+                bytes[0..FIELD_ELEMENT_LENGTH].copy_from_slice(&to_uniform_bytes(&u0, &mut rng));
+                bytes[FIELD_ELEMENT_LENGTH..].copy_from_slice(&to_uniform_bytes(&u1, &mut rng));
+
+                return Self(bytes);
+            }
+        }
+    }
+
+    /// Converts an `ElligatorSquaredPoint` to an `AffinePoint`.
+    /// The resulting point is the prime-order component of the sum
+    /// of both Simplified SWU images, and hence always lies in the
+    /// prime-order subgroup.
+    ///
+    /// **This operation is variable time with respect to the encoding.**
+    pub(crate) fn to_affine_vartime(self) -> AffinePoint {
+        let (prime_order_part, _) = self.to_curve().torsion_component();
+
+        AffinePoint::from(ProjectivePoint::from(prime_order_part))
+    }
+
+    /// Returns the sum of the images of both encoded `Fp6` elements by
+    /// the Simplified SWU map, including its $h$-torsion component.
+    fn to_curve(self) -> ProjectivePoint {
+        let u0 = from_uniform_bytes(&self.0[0..FIELD_ELEMENT_LENGTH]);
+        let u1 = from_uniform_bytes(&self.0[FIELD_ELEMENT_LENGTH..]);
+
+        ProjectivePoint::from(map_to_curve_simple_swu(&u0)).add_mixed(&map_to_curve_simple_swu(&u1))
+    }
+
+    /// Copies the bytes of this `ElligatorSquaredPoint`.
+    pub fn to_bytes(&self) -> [u8; 192] {
+        self.0
+    }
+
+    /// Views this `ElligatorSquaredPoint` as an array of bytes.
+    pub fn as_bytes(&self) -> &[u8; 192] {
+        &self.0
+    }
+
+    /// Interprets the provided bytes as an `ElligatorSquaredPoint`.
+    /// Any byte sequence represents a point of the prime-order subgroup.
+    pub fn from_bytes(bytes: &[u8; 192]) -> Self {
+        Self(*bytes)
+    }
+}
+
+// HELPER METHODS
+// ================================================================================================
+
+/// Outputs a random byte representation of an `Fp6` element, where each
+/// coordinate c is encoded as c + k.p, with k uniformly sampled among the
+/// values for which this sum fits in 128 bits.
+fn to_uniform_bytes(element: &Fp6, mut rng: impl RngCore) -> [u8; FIELD_ELEMENT_LENGTH] {
+    let mut bytes = [0u8; FIELD_ELEMENT_LENGTH];
+
+    for (chunk, &coordinate) in bytes
+        .chunks_mut(COORDINATE_LENGTH)
+        .zip(element.output_internal().iter())
+    {
+        let representative = loop {
+            // k is sampled over 65 bits, as 2^128 / p is slightly above 2^64
+            let mut k = [0u8; 16];
+            rng.fill_bytes(&mut k);
+            let k = u128::from_le_bytes(k) >> 63;
+
+            if let Some(r) = k
+                .checked_mul(MODULUS)
+                .and_then(|kp| kp.checked_add(coordinate as u128))
+            {
+                break r;
+            }
+        };
+
+        chunk.copy_from_slice(&representative.to_le_bytes());
+    }
+
+    bytes
+}
+
+/// Reduces a byte representation produced by `to_uniform_bytes`, or
+/// any other byte sequence of the same length, into an `Fp6` element.
+fn from_uniform_bytes(bytes: &[u8]) -> Fp6 {
+    let mut coordinates = [Fp::zero(); 6];
+
+    for (coordinate, chunk) in coordinates.iter_mut().zip(bytes.chunks(COORDINATE_LENGTH)) {
+        let mut tmp = [0u8; 16];
+        tmp.copy_from_slice(chunk);
+        *coordinate =
+            Fp::from_raw_unchecked(reduce_u128(u128::from_le_bytes(tmp))).make_canonical();
+    }
+
+    Fp6::from(coordinates)
+}
+
+/// Returns the preimage of a point by the Simplified SWU map stored
+/// in the given slot, if any.
+///
+/// A point (x, y) has at most four preimages, one for each root t of
+///   t^2 + t + 1/d = 0 (slots 0 and 1), when x is the first candidate of the map,
+///   t^2 + d.t + d = 0 (slots 2 and 3), when x is the second candidate of the map,
+/// with d = (x + B) / B and u^2 = t / Z, the sign of u matching the one of y.
+/// Preimages already stored in a previous slot are discarded.
+fn swu_preimage_vartime(point: &AffinePoint, slot: usize) -> Option<Fp6> {
+    // The map never outputs the point at infinity
+    if bool::from(point.is_identity()) {
+        return None;
+    }
+
+    let d = (point.x + B) * B.invert().unwrap();
+    let d_inv = Option::<Fp6>::from(d.invert());
+
+    // Coefficients (b, c) of the quadratic t^2 + b.t + c = 0
+    let (b, c) = if slot < 2 {
+        (Fp6::one(), d_inv?)
+    } else {
+        (d, d)
+    };
+
+    let sqrt_disc = Option::<Fp6>::from((b.square() - c.double().double()).sqrt())?;
+    if slot % 2 == 1 && bool::from(sqrt_disc.is_zero()) {
+        return None;
+    }
+
+    let sqrt_disc = if slot % 2 == 1 { -sqrt_disc } else { sqrt_disc };
+    let t = (sqrt_disc - b) * Fp6::from(2u8).invert().unwrap();
+
+    // Roots common to both quadratics are only kept in the first slots
+    if slot >= 2 {
+        if let Some(d_inv) = d_inv {
+            if bool::from((t.square() + t + d_inv).is_zero()) {
+                return None;
+            }
+        }
+    }
+
+    let mut u = Option::<Fp6>::from((t * Z.invert().unwrap()).sqrt())?;
+    if sgn0(&u).unwrap_u8() != sgn0(&point.y).unwrap_u8() {
+        u = -u;
+    }
+
+    if map_to_curve_simple_swu(&u) == *point {
+        Some(u)
+    } else {
+        None
+    }
+}
+
+// SERDE SERIALIZATION
+// ================================================================================================
+
+#[cfg(feature = "serialize")]
+impl Serialize for ElligatorSquaredPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(192)?;
+        for byte in self.0.iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for ElligatorSquaredPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ElligatorSquaredPointVisitor;
+
+        impl<'de> Visitor<'de> for ElligatorSquaredPointVisitor {
+            type Value = ElligatorSquaredPoint;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("192 bytes of data")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<ElligatorSquaredPoint, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; 192];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| {
+                        serde::de::Error::invalid_length(i, &"expected 192 bytes")
+                    })?;
+                }
+
+                Ok(ElligatorSquaredPoint(bytes))
+            }
+        }
+
+        deserializer.deserialize_tuple(192, ElligatorSquaredPointVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_decoding() {
+        let mut bytes = [0u8; 192];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let expected = AffinePoint::from_raw_coordinates([
+            Fp6::new([
+                0x4c4b419c9031e712,
+                0x127821f0df76a520,
+                0x329a82da610f0ba0,
+                0xc807314d341bc082,
+                0xc4f0907e286760ce,
+                0x948106fb7256c7ed,
+            ]),
+            Fp6::new([
+                0x05c80b277c32b0bf,
+                0x4a8ffb44591ee190,
+                0x667732f4b25b5e1d,
+                0x0f9a1c9bedcdba0b,
+                0x487fe3fcb685eec4,
+                0x10e56b1b7117a4da,
+            ]),
+        ]);
+
+        let encoding = ElligatorSquaredPoint::from_bytes(&bytes);
+        let point = AffinePoint::from(encoding.to_curve());
+        assert!(bool::from(point.is_on_curve()));
+        assert_eq!(point, expected);
+
+        let (prime_order_part, _) = ProjectivePoint::from(expected).torsion_component();
+        assert_eq!(
+            encoding.to_affine_vartime(),
+            AffinePoint::from(ProjectivePoint::from(prime_order_part))
+        );
+
+        // Any byte sequence decodes to a point of the prime-order subgroup
+        let mut rng = OsRng;
+        for _ in 0..10 {
+            rng.fill_bytes(&mut bytes);
+            let point = ElligatorSquaredPoint::from_bytes(&bytes).to_affine_vartime();
+            assert!(bool::from(point.is_on_curve()));
+            assert!(bool::from(point.is_torsion_free()));
+        }
+        let point = ElligatorSquaredPoint::from_bytes(&[0xff; 192]).to_affine_vartime();
+        assert!(bool::from(point.is_on_curve()));
+        assert!(bool::from(point.is_torsion_free()));
+    }
+
+    #[test]
+    fn test_encoding() {
+        let mut rng = OsRng;
+
+        let mut points: Vec<AffinePoint> = (0..10).map(|_| AffinePoint::random(&mut rng)).collect();
+        points[0] = AffinePoint::identity();
+        points[1] = AffinePoint::generator();
+
+        for point in points.iter() {
+            let encoding = ElligatorSquaredPoint::from_affine_vartime(point, &mut rng);
+            assert_eq!(encoding.to_affine_vartime(), *point);
+
+            // Encodings are randomized
+            let other = ElligatorSquaredPoint::from_affine_vartime(point, &mut rng);
+            assert_ne!(encoding, other);
+            assert_eq!(other.to_affine_vartime(), *point);
+        }
+
+        // Only the prime-order component of points outside
+        // of the subgroup is encoded
+        let point = map_to_curve_simple_swu(&Fp6::one());
+        assert!(!bool::from(point.is_torsion_free()));
+        let (prime_order_part, _) = ProjectivePoint::from(point).torsion_component();
+        let encoding = ElligatorSquaredPoint::from_affine_vartime(&point, &mut rng);
+        assert_eq!(
+            encoding.to_affine_vartime(),
+            AffinePoint::from(ProjectivePoint::from(prime_order_part))
+        );
+    }
+
+    #[test]
+    fn test_encoding_torsion() {
+        let mut rng = OsRng;
+
+        // Encodings of subgroup points carry a random h-torsion component,
+        // as do decodings of random byte strings, so that both cannot be
+        // told apart by checking whether f(u0) + f(u1) is torsion-free.
+        // A random h-torsion point is the identity with probability 1/h.
+        for _ in 0..20 {
+            let point = AffinePoint::random(&mut rng);
+            let encoding = ElligatorSquaredPoint::from_affine_vartime(&point, &mut rng);
+            assert!(!bool::from(encoding.to_curve().is_torsion_free()));
+            assert_eq!(encoding.to_affine_vartime(), point);
+        }
+    }
+
+    #[test]
+    fn test_swu_preimages() {
+        let mut rng = OsRng;
+
+        for _ in 0..20 {
+            let u = Fp6::random(&mut rng);
+            let point = map_to_curve_simple_swu(&u);
+
+            let preimages: Vec<Fp6> = (0..4)
+                .filter_map(|slot| swu_preimage_vartime(&point, slot))
+                .collect();
+            assert!(preimages.contains(&u));
+
+            for (i, preimage) in preimages.iter().enumerate() {
+                assert_eq!(map_to_curve_simple_swu(preimage), point);
+                assert!(!preimages[i + 1..].contains(preimage));
+            }
+        }
+
+        for slot in 0..4 {
+            assert!(swu_preimage_vartime(&AffinePoint::identity(), slot).is_none());
+        }
+    }
+
+    #[test]
+    fn test_uniform_bytes() {
+        let mut rng = OsRng;
+
+        for _ in 0..10 {
+            let element = Fp6::random(&mut rng);
+            let bytes = to_uniform_bytes(&element, &mut rng);
+            assert_eq!(from_uniform_bytes(&bytes), element);
+        }
+
+        // The most significant bit of representatives is not constant
+        let element = Fp6::one();
+        let mut high_bits = 0;
+        for _ in 0..64 {
+            let bytes = to_uniform_bytes(&element, &mut rng);
+            high_bits |= 1 << (bytes[COORDINATE_LENGTH - 1] >> 7);
+        }
+        assert_eq!(high_bits, 0b11);
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn test_serde() {
+        let mut rng = OsRng;
+        let point = AffinePoint::random(&mut rng);
+        let encoding = ElligatorSquaredPoint::from_affine_vartime(&point, &mut rng);
+
+        let encoded = bincode::serialize(&encoding).unwrap();
+        let parsed: ElligatorSquaredPoint = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(parsed, encoding);
+
+        // Check that the encoding is 192 bytes exactly
+        assert_eq!(encoded.len(), 192);
+
+        // Check that invalid encodings fail
+        assert!(bincode::deserialize::<ElligatorSquaredPoint>(&encoded[0..191]).is_err());
+    }
+}
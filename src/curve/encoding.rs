@@ -34,6 +34,18 @@ impl Default for CompressedPoint {
     }
 }
 
+impl AsRef<[u8]> for CompressedPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for CompressedPoint {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
 impl ConstantTimeEq for CompressedPoint {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.as_bytes().ct_eq(other.as_bytes())
@@ -158,6 +170,18 @@ impl Default for UncompressedPoint {
     }
 }
 
+impl AsRef<[u8]> for UncompressedPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for UncompressedPoint {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
 impl ConstantTimeEq for UncompressedPoint {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.as_bytes().ct_eq(other.as_bytes())
@@ -0,0 +1,545 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module implements hashing of arbitrary byte strings to points of
+//! the curve, following the `hash_to_curve` and `encode_to_curve` procedures
+//! of RFC 9380 (https://www.rfc-editor.org/rfc/rfc9380.html#section-3).
+//!
+//! As the curve coefficient A is non-zero, the Simplified Shallue-van de
+//! Woestijne-Ulas method (section 6.6.2) is applied directly on the curve,
+//! without any isogeny.
+
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+use crate::hash_to_field::HashToField;
+use crate::{AffinePoint, Fp6, ProjectivePoint, SubgroupAffinePoint, SubgroupPoint};
+
+use super::B;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Suite identifier for the `hash_to_curve` procedure, to be used as suffix
+/// of application-specific domain separation tags.
+pub const HASH_TO_CURVE_SUITE_ID: &[u8] = b"CHEETAH_XMD:SHA-256_SSWU_RO_";
+
+/// Suite identifier for the `encode_to_curve` procedure, to be used as suffix
+/// of application-specific domain separation tags.
+pub const ENCODE_TO_CURVE_SUITE_ID: &[u8] = b"CHEETAH_XMD:SHA-256_SSWU_NU_";
+
+/// Domain separation tag used for deriving generators with `derive_generators`
+const GENERATORS_DST: &[u8] = b"CHEETAH-V01-GENERATORS-with-CHEETAH_XMD:SHA-256_SSWU_RO_";
+
+/// Z = -(u + 10), obtained with the `find_z_sswu` procedure of RFC 9380
+pub(super) const Z: Fp6 = Fp6::new([18446744069414584311, 18446744069414584320, 0, 0, 0, 0]);
+
+/// -B / A = -(u + 395)
+const MINUS_B_OVER_A: Fp6 = Fp6::new([18446744069414583926, 18446744069414584320, 0, 0, 0, 0]);
+
+/// B / (Z.A)
+const B_OVER_ZA: Fp6 = Fp6::new([
+    9249372902479206381,
+    2764411523634996226,
+    10791605289285250970,
+    17367583540486059224,
+    5641939273717227806,
+    10503852514277027812,
+]);
+
+// HELPER METHODS
+// ================================================================================================
+
+/// Returns the `sgn0` of an element, i.e. the parity of its first
+/// non-zero coordinate, as specified in section 4.1 of RFC 9380.
+pub(super) fn sgn0(element: &Fp6) -> Choice {
+    let mut sign = Choice::from(0u8);
+    let mut zero = Choice::from(1u8);
+
+    for coordinate in element.output_internal() {
+        let sign_i = Choice::from((coordinate & 1) as u8);
+        let zero_i = coordinate.ct_eq(&0);
+        sign |= zero & sign_i;
+        zero &= zero_i;
+    }
+
+    sign
+}
+
+/// Maps a field element to a point of the curve with the Simplified
+/// SWU method, in constant time. The output point may not be in the
+/// prime-order subgroup.
+pub(crate) fn map_to_curve_simple_swu(u: &Fp6) -> AffinePoint {
+    let zu2 = Z * u.square();
+
+    // tv1 = inv0(Z^2.u^4 + Z.u^2)
+    let tv1 = zu2.square() + zu2;
+    let tv1 = tv1.invert().unwrap_or(Fp6::zero());
+
+    // x1 = (-B / A).(1 + tv1), or B / (Z.A) in the exceptional case tv1 = 0
+    let x1 = MINUS_B_OVER_A * (Fp6::one() + tv1);
+    let x1 = Fp6::conditional_select(&x1, &B_OVER_ZA, tv1.is_zero());
+    let gx1 = (x1.square() + Fp6::one()) * x1 + B;
+
+    let x2 = zu2 * x1;
+    let gx2 = (x2.square() + Fp6::one()) * x2 + B;
+
+    // Exactly one of gx1 and gx2 is a square
+    let y1 = gx1.sqrt();
+    let y2 = gx2.sqrt().unwrap_or(Fp6::zero());
+    let is_gx1_square = y1.is_some();
+
+    let x = Fp6::conditional_select(&x2, &x1, is_gx1_square);
+    let mut y = Fp6::conditional_select(&y2, &y1.unwrap_or(Fp6::zero()), is_gx1_square);
+    y.conditional_negate(sgn0(u) ^ sgn0(&y));
+
+    AffinePoint::from_raw_coordinates([x, y])
+}
+
+// HASH TO CURVE
+// ================================================================================================
+
+impl ProjectivePoint {
+    /// Hashes a message `msg` with domain separation tag `dst` to a point
+    /// of the prime-order subgroup, following the `hash_to_curve` procedure
+    /// of RFC 9380 with the suite `CHEETAH_XMD:SHA-256_SSWU_RO_`.
+    ///
+    /// The output distribution is indistinguishable from uniformly random
+    /// points of the subgroup, and the discrete logarithm of the output
+    /// is unknown, which makes it suitable for deriving independent generators.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> ProjectivePoint {
+        let mut u = [Fp6::zero(); 2];
+        Fp6::hash_to_field(msg, dst, &mut u);
+
+        let q0 = map_to_curve_simple_swu(&u[0]);
+        let q1 = map_to_curve_simple_swu(&u[1]);
+
+        ProjectivePoint::from(q0).add_mixed(&q1).clear_cofactor()
+    }
+
+    /// Encodes a message `msg` with domain separation tag `dst` to a point
+    /// of the prime-order subgroup, following the `encode_to_curve` procedure
+    /// of RFC 9380 with the suite `CHEETAH_XMD:SHA-256_SSWU_NU_`.
+    ///
+    /// This is about twice as fast as `hash_to_curve`, but the output
+    /// distribution is not uniform and only covers about half of the subgroup.
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> ProjectivePoint {
+        let mut u = [Fp6::zero(); 1];
+        Fp6::hash_to_field(msg, dst, &mut u);
+
+        ProjectivePoint::from(map_to_curve_simple_swu(&u[0])).clear_cofactor()
+    }
+}
+
+impl SubgroupPoint {
+    /// Hashes a message `msg` with domain separation tag `dst` to a point
+    /// of the prime-order subgroup. See `ProjectivePoint::hash_to_curve`.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> SubgroupPoint {
+        SubgroupPoint(ProjectivePoint::hash_to_curve(msg, dst))
+    }
+
+    /// Encodes a message `msg` with domain separation tag `dst` to a point
+    /// of the prime-order subgroup. See `ProjectivePoint::encode_to_curve`.
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> SubgroupPoint {
+        SubgroupPoint(ProjectivePoint::encode_to_curve(msg, dst))
+    }
+}
+
+// GENERATORS DERIVATION
+// ================================================================================================
+
+/// Deterministically derives `n` points of the prime-order subgroup from a
+/// label `domain_tag`, with no known discrete logarithm relation between them,
+/// nor with the curve generator.
+///
+/// The i-th point is obtained as `hash_to_curve(domain_tag || I2OSP(i, 8), DST)`,
+/// with `I2OSP(i, 8)` the 8-byte big-endian encoding of i and the domain separation
+/// tag `DST = "CHEETAH-V01-GENERATORS-with-CHEETAH_XMD:SHA-256_SSWU_RO_"`.
+/// Hence deriving `m < n` points with the same label returns the first `m` points.
+///
+/// Precomputed tables for efficient multiscalar multiplications with these
+/// points can be obtained with `FixedBaseMsmTable::from_domain_tag`.
+pub fn derive_generators(domain_tag: &[u8], n: usize) -> Vec<SubgroupAffinePoint> {
+    let derive = |i: usize| {
+        let mut msg = Vec::with_capacity(domain_tag.len() + 8);
+        msg.extend_from_slice(domain_tag);
+        msg.extend_from_slice(&(i as u64).to_be_bytes());
+
+        ProjectivePoint::hash_to_curve(&msg, GENERATORS_DST)
+    };
+
+    #[cfg(feature = "parallel")]
+    let points: Vec<ProjectivePoint> = (0..n).into_par_iter().map(derive).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let points: Vec<ProjectivePoint> = (0..n).map(derive).collect();
+
+    let mut points_affine = vec![AffinePoint::identity(); n];
+    ProjectivePoint::batch_normalize(&points, &mut points_affine);
+
+    points_affine.into_iter().map(SubgroupAffinePoint).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DST_RO: &[u8] = b"QUUX-V01-CS02-with-CHEETAH_XMD:SHA-256_SSWU_RO_";
+    const DST_NU: &[u8] = b"QUUX-V01-CS02-with-CHEETAH_XMD:SHA-256_SSWU_NU_";
+
+    fn point(x: [u64; 6], y: [u64; 6]) -> AffinePoint {
+        let point = AffinePoint::from_raw_coordinates([Fp6::new(x), Fp6::new(y)]);
+        assert!(bool::from(point.is_on_curve()));
+
+        point
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(MINUS_B_OVER_A, -B);
+        assert_eq!(B_OVER_ZA * Z, B);
+
+        // Z is a non-square distinct from -1
+        assert!(bool::from(Z.sqrt().is_none()));
+        assert_ne!(Z, -Fp6::one());
+
+        // g(B / (Z.A)) is a square
+        let gx = (B_OVER_ZA.square() + Fp6::one()) * B_OVER_ZA + B;
+        assert!(bool::from(gx.sqrt().is_some()));
+    }
+
+    #[test]
+    fn test_sgn0() {
+        assert!(!bool::from(sgn0(&Fp6::zero())));
+        assert!(bool::from(sgn0(&Fp6::one())));
+        assert!(!bool::from(sgn0(&Fp6::new([2, 1, 0, 0, 0, 0]))));
+        assert!(bool::from(sgn0(&Fp6::new([0, 3, 0, 0, 0, 0]))));
+        assert!(!bool::from(sgn0(&-Fp6::one())));
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        let vectors = [
+            (
+                Fp6::zero(),
+                point(
+                    [
+                        0x805c5fa6476597ed,
+                        0x265d29a25f42a402,
+                        0x95c37bd59012ef9a,
+                        0xf1060d367197b4d8,
+                        0x4e4c31e08e3da11e,
+                        0x91c52e35be936fe4,
+                    ],
+                    [
+                        0xadcfc047bb323664,
+                        0xa4fbd9c70bb45bab,
+                        0x5928f54c3eecbdc5,
+                        0xd57e84591ccbfb87,
+                        0xbaced521fb7a1bc1,
+                        0x8c7dbd74d1359f57,
+                    ],
+                ),
+            ),
+            (
+                Fp6::one(),
+                point(
+                    [
+                        0xd6eecb4857f6635b,
+                        0xcbac94138472dbd1,
+                        0x5b25b6a80dba5989,
+                        0xd96d965e375d1280,
+                        0x92820bbbf9d936d6,
+                        0x7df18cea8ee7dd77,
+                    ],
+                    [
+                        0xe5b63c9863362def,
+                        0xac7383238e6bb6cf,
+                        0x207e63c36b6ca779,
+                        0x018379031c3259cb,
+                        0x818bdf9e4e88a1b7,
+                        0xc8839a8c7fa9c115,
+                    ],
+                ),
+            ),
+            (
+                Fp6::new([5, 6, 7, 8, 9, 10]),
+                point(
+                    [
+                        0x2d846bc6dd50b761,
+                        0x9697e311c4448317,
+                        0x039cd5ed9ab44d63,
+                        0xbd664aacd8b979ee,
+                        0x2b82020d851a3a6b,
+                        0xdb52a312c6cfe1e2,
+                    ],
+                    [
+                        0x4aadeb0ed328e86f,
+                        0xc5663ecedc29a170,
+                        0x51d916d577afcbcb,
+                        0xf09bd53b62991f45,
+                        0x8ed949dae2591a08,
+                        0x608f67b1e0b3826c,
+                    ],
+                ),
+            ),
+        ];
+
+        for (u, expected) in vectors.iter() {
+            assert_eq!(&map_to_curve_simple_swu(u), expected);
+        }
+
+        let mut rng = rand_core::OsRng;
+        for _ in 0..20 {
+            let u = Fp6::random(&mut rng);
+            let point = map_to_curve_simple_swu(&u);
+            assert!(bool::from(point.is_on_curve()));
+            assert!(!bool::from(point.is_identity()));
+            assert_eq!(sgn0(&u).unwrap_u8(), sgn0(&point.y).unwrap_u8());
+            assert_eq!(map_to_curve_simple_swu(&-u), -point);
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let vectors: [(&[u8], AffinePoint); 3] = [
+            (
+                b"",
+                point(
+                    [
+                        0x2dab38411b265ecd,
+                        0xf4c6c1ea81065566,
+                        0x484aa268693baffe,
+                        0x66fa6faae699ed4b,
+                        0xb5cbf7630ce85981,
+                        0x828e74039aee776b,
+                    ],
+                    [
+                        0x4502c8ab90fe353e,
+                        0xef81dff2d23a6321,
+                        0x45d27a46180a355c,
+                        0xe0c9604f9ce27987,
+                        0x9a656056ebcb8d31,
+                        0x94d143b69a03c2e3,
+                    ],
+                ),
+            ),
+            (
+                b"abc",
+                point(
+                    [
+                        0x3dedec21fef76ba6,
+                        0x3bef785063d8f903,
+                        0xe7d6c9ac84839ea2,
+                        0xb3e5b5d3e9a7a9d4,
+                        0x6d0c90556b26d3a4,
+                        0x3e1c828b8809c71c,
+                    ],
+                    [
+                        0xd501fb8b55d6e793,
+                        0x5c3df4f21ff60075,
+                        0x5f77f6d44a0503da,
+                        0xf4d1e83e478794d8,
+                        0x9f6a924639c4e1e5,
+                        0xcf3166fdc73b4516,
+                    ],
+                ),
+            ),
+            (
+                b"abcdef0123456789",
+                point(
+                    [
+                        0x85ae1acd9ca4a68c,
+                        0x70a0e07073d2e88d,
+                        0x2fa50cdd1ef644e6,
+                        0x667d17c2305f96bf,
+                        0x559e4bef8b802078,
+                        0xc2d731778ecd3805,
+                    ],
+                    [
+                        0x808dbfde91a156b2,
+                        0x4966953aa3fc0eac,
+                        0xd41f9e090e18f08f,
+                        0x449a7ef48e26e436,
+                        0x1cb5b6b6d06b3d6b,
+                        0x01d2e2de376d17d1,
+                    ],
+                ),
+            ),
+        ];
+
+        for (msg, expected) in vectors.iter() {
+            let point = ProjectivePoint::hash_to_curve(msg, DST_RO);
+            assert!(bool::from(point.is_torsion_free()));
+            assert_eq!(AffinePoint::from(point), *expected);
+            assert_eq!(SubgroupPoint::hash_to_curve(msg, DST_RO).0, point);
+        }
+
+        // Domain separation
+        assert_ne!(
+            ProjectivePoint::hash_to_curve(b"abc", DST_RO),
+            ProjectivePoint::hash_to_curve(b"abc", DST_NU)
+        );
+    }
+
+    #[test]
+    fn test_encode_to_curve() {
+        let vectors: [(&[u8], AffinePoint); 3] = [
+            (
+                b"",
+                point(
+                    [
+                        0x0a02ac4cfb70dd31,
+                        0xcf3549847a921853,
+                        0x13f4c1403423879f,
+                        0x12b298b16d291a69,
+                        0x498a1c9f48d2497b,
+                        0x36575837dbbf2d95,
+                    ],
+                    [
+                        0xc6cfebd925a6526b,
+                        0x31f7ebc0969b872c,
+                        0xfb8d3c88087aad57,
+                        0xde518d51fb967d4b,
+                        0x9f365bc1789a864c,
+                        0xcf10d72ed5cd30ff,
+                    ],
+                ),
+            ),
+            (
+                b"abc",
+                point(
+                    [
+                        0xd7985b5f43ce91f0,
+                        0xeeb97419a83c3b67,
+                        0x0f69be003e33ebef,
+                        0xa1a8f8b1f4b33224,
+                        0x2c77929348ac2389,
+                        0xbacf309569ada452,
+                    ],
+                    [
+                        0xa80f1e8900132e77,
+                        0xff3abb07f2a80874,
+                        0xcf310012180d16b2,
+                        0x7a29f947d89e18b2,
+                        0xc33322ff8808e2bc,
+                        0x155b806810a44b5f,
+                    ],
+                ),
+            ),
+            (
+                b"abcdef0123456789",
+                point(
+                    [
+                        0x7df5c70034f9d2a9,
+                        0xb26ea4ceb0132380,
+                        0xd47f1289cf4e2e82,
+                        0x149c185b340baddb,
+                        0x44029e81eb7aca9f,
+                        0x5506b188f1a0c565,
+                    ],
+                    [
+                        0x2c97884cf7da9f1a,
+                        0xd5e18fb346f6e758,
+                        0x54c769f42bab277d,
+                        0xc8032bbe6d996ada,
+                        0x0ffd5a2eef7ae7b5,
+                        0x3e821cae87ee7308,
+                    ],
+                ),
+            ),
+        ];
+
+        for (msg, expected) in vectors.iter() {
+            let point = ProjectivePoint::encode_to_curve(msg, DST_NU);
+            assert!(bool::from(point.is_torsion_free()));
+            assert_eq!(AffinePoint::from(point), *expected);
+            assert_eq!(SubgroupPoint::encode_to_curve(msg, DST_NU).0, point);
+        }
+    }
+
+    #[test]
+    fn test_derive_generators() {
+        let expected = [
+            point(
+                [
+                    0x10c82c0447002f1e,
+                    0x513f53b412c3bd91,
+                    0x8f3ab4479e8f8bfe,
+                    0x5383edbab49eb76a,
+                    0xcdfb4a394abbaefd,
+                    0xa5efca2079b8f779,
+                ],
+                [
+                    0x854ab0e323fc60bb,
+                    0xf8f1e8c88be8e44b,
+                    0x870faa5ebe9d0fc5,
+                    0x6722f6eac1c93759,
+                    0x1433795b3952d0be,
+                    0xaade436321211412,
+                ],
+            ),
+            point(
+                [
+                    0x61b9d22629a941c2,
+                    0x676d4eb19fba6f80,
+                    0x768a18a92117f3f2,
+                    0x4b9b776b9bb80af1,
+                    0x29e4db671b167002,
+                    0xb6462f4a10bc8051,
+                ],
+                [
+                    0xce17e053997efa39,
+                    0x833f55a2041fc180,
+                    0xe9ce8fa394839f03,
+                    0xd38e3037047f601b,
+                    0x385bbe59576097c0,
+                    0x5f8797585b51bf76,
+                ],
+            ),
+            point(
+                [
+                    0x27a73fdf82f4f0e0,
+                    0xdfac133506f6f2f0,
+                    0xd0e5366bdadde956,
+                    0x80c5330b40aafa7b,
+                    0x7276bee1c9478069,
+                    0x735ee6e01ab22ec9,
+                ],
+                [
+                    0xcba3864eba00e73e,
+                    0xaa4c60780c6859d8,
+                    0x148c0b3ef34ccd67,
+                    0x4fc65a1c9f889b33,
+                    0x14c8b4d8679cc598,
+                    0xa14fe96f349c7ac4,
+                ],
+            ),
+        ];
+
+        let generators = derive_generators(b"cheetah-test", 3);
+        assert_eq!(generators.len(), 3);
+        for (generator, expected) in generators.iter().zip(expected.iter()) {
+            assert_eq!(AffinePoint::from(generator), *expected);
+            assert!(bool::from(expected.is_torsion_free()));
+        }
+
+        // Prefixes are consistent
+        assert_eq!(derive_generators(b"cheetah-test", 2), generators[0..2]);
+        assert!(derive_generators(b"cheetah-test", 0).is_empty());
+
+        // Different labels yield different generators
+        let other = derive_generators(b"cheetah-test-2", 3);
+        for (a, b) in generators.iter().zip(other.iter()) {
+            assert_ne!(a, b);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides an x-only co-Z Montgomery ladder for the
+//! cheetah curve, following the approach of Hutter, Joye and Sierra
+//! (https://eprint.iacr.org/2011/338.pdf).
+//!
+//! Both ladder points share a common, never computed, Z coordinate.
+//! The state is made of their X coordinates along with TD = x_D.Z,
+//! Ta = A.Z^2 and Tb = 4B.Z^3, where x_D is the x-coordinate of their
+//! difference, i.e. of the input point.
+
+use super::B;
+
+use crate::scalar::M;
+use crate::{AffinePoint, Fp6, Scalar};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+// Number of ladder steps, the scalar being recoded with its bit 255 set
+const NUM_STEPS: usize = 255;
+
+impl AffinePoint {
+    /// Computes the x-coordinate of [k]P, where P is a point of the prime-order
+    /// subgroup given by its x-coordinate only, and k is given as byte
+    /// representation of a `Scalar` element, with an x-only co-Z Montgomery ladder.
+    ///
+    /// This avoids the square root needed to decompress P, and does not depend
+    /// on the sign of its y-coordinate as x([k]P) = x([k](-P)).
+    ///
+    /// Returns `None` if `x` lies on the quadratic twist, i.e. if it is not the
+    /// x-coordinate of a point of the curve, or if [k]P is the point at infinity.
+    /// The result is only guaranteed to be x([k]P) for points of the prime-order
+    /// subgroup, as k is internally replaced by k + q or k + 2q to obtain a
+    /// constant-length scalar.
+    pub fn multiply_x_only(x: &Fp6, by: &[u8; 32]) -> CtOption<Fp6> {
+        let k = Scalar::from_bytes_non_canonical(by);
+        let bits = fixed_length_scalar(&k);
+
+        let gx = (x.square() + Fp6::one()) * x + B;
+        let is_on_curve = gx.is_square();
+
+        // x([2]P) = ((x^2 - A)^2 - 8Bx) / 4(x^3 + Ax + B)
+        let x2_num = (x.square() - Fp6::one()).square() - (B * x).double().double().double();
+        let x2_den = gx.double().double();
+
+        // Initial state (P, [2]P) with common Z = 4(x^3 + Ax + B)
+        let mut x0 = x * x2_den;
+        let mut x1 = x2_num;
+        let mut td = x0;
+        let mut ta = x2_den.square();
+        let mut tb = (ta * x2_den * B).double().double();
+
+        for i in (0..NUM_STEPS).rev() {
+            let bit = Choice::from(((bits[i / 64] >> (i % 64)) & 1) as u8);
+
+            // (R0, R1) is updated to (R0 + R1, [2]R1) if the bit is set,
+            // and to ([2]R0, R0 + R1) otherwise.
+            Fp6::conditional_swap(&mut x0, &mut x1, !bit);
+
+            let a = (x0 - x1).square();
+            let add_num = ((x0 * x1 + ta) * (x0 + x1)).double() + tb - td * a;
+            let x1_sq = x1.square();
+            let dbl_num = (x1_sq - ta).square() - (tb * x1).double();
+            let dbl_den = (x1 * (x1_sq + ta)).double().double() + tb;
+
+            // Bring both results to the common Z' = lambda.Z
+            let lambda = a * dbl_den;
+            x0 = add_num * dbl_den;
+            x1 = dbl_num * a;
+            td *= lambda;
+            let lambda_sq = lambda.square();
+            ta *= lambda_sq;
+            tb *= lambda_sq * lambda;
+
+            Fp6::conditional_swap(&mut x0, &mut x1, !bit);
+        }
+
+        // The ladder degenerates when one of its points reaches the point
+        // at infinity, which for points of the prime-order subgroup only
+        // happens for k in {0, 1, -1, -2}. The last three are handled
+        // separately, as x([±1]P) = x and x([-2]P) = x([2]P).
+        let is_one = k.ct_eq(&Scalar::one()) | k.ct_eq(&-Scalar::one());
+        let is_minus_two = k.ct_eq(&-Scalar::from(2u64));
+
+        // x([k]P) = X0 / Z = X0.x_D / TD
+        let mut num = x0 * x;
+        let mut den = td;
+        num.conditional_assign(x, is_one);
+        den.conditional_assign(&Fp6::one(), is_one);
+        num.conditional_assign(&x2_num, is_minus_two);
+        den.conditional_assign(&x2_den, is_minus_two);
+
+        let result = num * den.invert().unwrap_or(Fp6::zero());
+
+        CtOption::new(result, is_on_curve & !den.is_zero() & !k.is_zero())
+    }
+}
+
+/// Returns the little-endian limbs of k + q or k + 2q, whichever has its
+/// bit 255 set, so that the ladder can process a constant number of bits.
+fn fixed_length_scalar(k: &Scalar) -> [u64; 4] {
+    let k = k.to_bytes();
+
+    let mut k_plus_q = [0u64; 4];
+    let mut k_plus_2q = [0u64; 4];
+    let mut carry = 0u128;
+    let mut carry_2 = 0u128;
+    for i in 0..4 {
+        let limb = u64::from_le_bytes(k[8 * i..8 * i + 8].try_into().unwrap()) as u128;
+
+        let tmp = limb + M.0[i] as u128 + carry;
+        k_plus_q[i] = tmp as u64;
+        carry = tmp >> 64;
+
+        let tmp = k_plus_q[i] as u128 + M.0[i] as u128 + carry_2;
+        k_plus_2q[i] = tmp as u64;
+        carry_2 = tmp >> 64;
+    }
+
+    // As q < 2^255 < 2q, k + 2q < 2^256 whenever k + q < 2^255
+    let is_long = Choice::from((k_plus_q[3] >> 63) as u8);
+    let mut result = k_plus_2q;
+    for (r, l) in result.iter_mut().zip(k_plus_q.iter()) {
+        r.conditional_assign(l, is_long);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProjectivePoint;
+    use alloc::vec;
+    use rand_core::OsRng;
+
+    fn expected(point: &AffinePoint, k: &Scalar) -> Option<Fp6> {
+        let product = AffinePoint::from(point * k);
+        if bool::from(product.is_identity()) {
+            None
+        } else {
+            Some(product.x)
+        }
+    }
+
+    #[test]
+    fn test_multiply_x_only() {
+        let mut rng = OsRng;
+
+        let mut points = vec![AffinePoint::generator()];
+        for _ in 0..5 {
+            points.push(AffinePoint::from(ProjectivePoint::random(&mut rng)));
+        }
+
+        let mut scalars = vec![
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::from(2u64),
+            Scalar::from(3u64),
+            -Scalar::one(),
+            -Scalar::from(2u64),
+            -Scalar::from(3u64),
+        ];
+        for _ in 0..10 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+
+        for point in points.iter() {
+            for k in scalars.iter() {
+                let result = AffinePoint::multiply_x_only(&point.x, &k.to_bytes());
+                assert_eq!(Option::<Fp6>::from(result), expected(point, k));
+            }
+        }
+
+        // Non-canonical scalars are reduced
+        let bytes = [0xff; 32];
+        let k = Scalar::from_bytes_non_canonical(&bytes);
+        assert_eq!(
+            AffinePoint::multiply_x_only(&points[0].x, &bytes).unwrap(),
+            expected(&points[0], &k).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_multiply_x_only_twist() {
+        let mut rng = OsRng;
+        let k = Scalar::random(&mut rng).to_bytes();
+
+        let mut twist_inputs = 0;
+        for _ in 0..20 {
+            let x = Fp6::random(&mut rng);
+            let on_curve = AffinePoint::from_x(&x).is_some();
+            let result = AffinePoint::multiply_x_only(&x, &k);
+            assert_eq!(result.is_some().unwrap_u8(), on_curve.unwrap_u8());
+
+            twist_inputs += 1 - on_curve.unwrap_u8();
+        }
+        assert!(twist_inputs > 0);
+
+        // x = 0 is on the twist, as B is not a square
+        assert!(bool::from(
+            AffinePoint::multiply_x_only(&Fp6::zero(), &k).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_fixed_length_scalar() {
+        let mut rng = OsRng;
+
+        for k in [Scalar::zero(), -Scalar::one(), Scalar::random(&mut rng)] {
+            let bits = fixed_length_scalar(&k);
+            assert_eq!(bits[3] >> 63, 1);
+
+            // The recoded scalar is congruent to k modulo q
+            let mut bytes = [0u8; 32];
+            for (chunk, limb) in bytes.chunks_mut(8).zip(bits.iter()) {
+                chunk.copy_from_slice(&limb.to_le_bytes());
+            }
+            assert_eq!(Scalar::from_bytes_non_canonical(&bytes), k);
+        }
+    }
+}
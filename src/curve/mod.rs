@@ -11,14 +11,26 @@
 //! of characteristic p = 2^64 - 2^32 + 1.
 
 mod affine;
+mod elligator;
 mod encoding;
+mod hash_to_curve;
 mod jacobian;
+mod ladder;
+mod pippenger;
 mod projective;
+mod subgroup;
+mod torsion;
+mod twist;
 
 pub use affine::AffinePoint;
+pub use elligator::ElligatorSquaredPoint;
 pub use encoding::{CompressedPoint, UncompressedPoint};
+pub use hash_to_curve::{derive_generators, ENCODE_TO_CURVE_SUITE_ID, HASH_TO_CURVE_SUITE_ID};
 pub use jacobian::{JacobianPoint, ModifiedJacobianPoint};
 pub use projective::ProjectivePoint;
+pub use subgroup::{SubgroupAffinePoint, SubgroupPoint};
+pub use torsion::{COFACTOR, COFACTOR_FACTORS};
+pub use twist::{TwistPoint, TWIST_ORDER};
 
 // HELPER METHODS
 // ================================================================================================
@@ -93,3 +105,28 @@ pub(crate) const fn mul_by_3b(a: &Fp6) -> Fp6 {
         c5,
     }
 }
+
+/// Returns a point of the curve lying outside of the
+/// prime-order subgroup, shared by the tests of all point types.
+#[cfg(test)]
+pub(crate) fn non_torsion_free_point() -> AffinePoint {
+    AffinePoint {
+        x: Fp6 {
+            c0: Fp(0x9bfcd3244afcb637),
+            c1: Fp(0x39005e478830b187),
+            c2: Fp(0x7046f1c03b42c6cc),
+            c3: Fp(0xb5eeac99193711e5),
+            c4: Fp(0x7fd272e724307b98),
+            c5: Fp(0xcc371dd6dd5d8625),
+        },
+        y: Fp6 {
+            c0: Fp(0x9d03fdc216dfaae8),
+            c1: Fp(0xbf4ade2a7665d9b8),
+            c2: Fp(0xf08b022d5b3262b7),
+            c3: Fp(0x2eaf583a3cf15c6f),
+            c4: Fp(0xa92531e4b1338285),
+            c5: Fp(0x5b8157814141a7a7),
+        },
+        infinity: subtle::Choice::from(0u8),
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module implements the Pippenger bucket method for
+//! multiscalar multiplications over large batches of points.
+
+use crate::recoding::to_signed_radix_i32;
+use crate::{AffinePoint, ProjectivePoint};
+
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Maximum window width, to bound the memory used by buckets.
+const MAX_WINDOW_WIDTH: usize = 16;
+
+/// Returns the window width minimizing the number of point additions
+/// for a multiscalar multiplication of `n` points, which is roughly
+/// ln(n) + 2.
+pub(crate) fn optimal_window_width(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // ln(n) ~ log2(n) * 69 / 100
+        let log2_n = (usize::BITS - n.leading_zeros()) as usize;
+        (log2_n * 69 / 100 + 2).min(MAX_WINDOW_WIDTH)
+    }
+}
+
+/// Performs the multiscalar multiplication ∑ s[i].p[i] with the s[i] given
+/// as byte representations of `Scalar` elements, using the bucket method
+/// with signed digits of width `c`.
+///
+/// **This operation is variable time with respect
+/// to the scalars.** If the scalars are fixed,
+/// this operation is effectively constant time.
+pub(crate) fn multiply_many_vartime(
+    points: &[AffinePoint],
+    scalars: &[[u8; 32]],
+    c: usize,
+) -> ProjectivePoint {
+    debug_assert!(c >= 2);
+    debug_assert!(c <= MAX_WINDOW_WIDTH);
+
+    let num_windows = 256usize.div_ceil(c);
+    let digits: Vec<Vec<i32>> = scalars.iter().map(|s| to_signed_radix_i32(s, c)).collect();
+
+    // Windows are processed independently, before being
+    // combined together with a single doubling chain.
+    #[cfg(feature = "parallel")]
+    let window_sums: Vec<ProjectivePoint> = (0..num_windows)
+        .into_par_iter()
+        .map(|window| window_sum(points, &digits, window, c))
+        .collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let window_sums: Vec<ProjectivePoint> = (0..num_windows)
+        .map(|window| window_sum(points, &digits, window, c))
+        .collect();
+
+    let mut acc = ProjectivePoint::identity();
+    for window_sum in window_sums.iter().rev() {
+        acc = acc.double_multi(c as u32);
+        acc += window_sum;
+    }
+
+    acc
+}
+
+/// Computes ∑ d[i].p[i] where the d[i] are the signed digits of index
+/// `window` of the scalars, by accumulating points into buckets.
+fn window_sum(
+    points: &[AffinePoint],
+    digits: &[Vec<i32>],
+    window: usize,
+    c: usize,
+) -> ProjectivePoint {
+    // Digits lie in [-2^(c-1); 2^(c-1)], except in the top window where
+    // non-canonical scalars may yield digits up to 2^c. We hence allocate
+    // as many buckets as the largest absolute digit of this window, negating
+    // the points associated to negative digits.
+    let num_buckets = digits
+        .iter()
+        .map(|digits| digits[window].unsigned_abs() as usize)
+        .max()
+        .unwrap_or(0);
+    debug_assert!(num_buckets <= 1 << c);
+    let mut buckets = vec![ProjectivePoint::identity(); num_buckets];
+
+    for (point, digits) in points.iter().zip(digits) {
+        let digit = digits[window];
+        if digit > 0 {
+            let bucket = &mut buckets[(digit - 1) as usize];
+            *bucket = bucket.add_mixed(point);
+        } else if digit < 0 {
+            let bucket = &mut buckets[(-digit - 1) as usize];
+            *bucket = bucket.add_mixed(&-point);
+        }
+    }
+
+    // Compute ∑ (j + 1).buckets[j] with a running sum, starting
+    // from the highest bucket.
+    let mut running_sum = ProjectivePoint::identity();
+    let mut sum = ProjectivePoint::identity();
+    for bucket in buckets.iter().rev() {
+        running_sum += bucket;
+        sum += running_sum;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_optimal_window_width() {
+        assert_eq!(optimal_window_width(0), 3);
+        assert_eq!(optimal_window_width(31), 3);
+        assert_eq!(optimal_window_width(1 << 10), 9);
+        assert_eq!(optimal_window_width(1 << 16), 13);
+        assert_eq!(optimal_window_width(usize::MAX), MAX_WINDOW_WIDTH);
+    }
+
+    #[test]
+    fn test_multiply_many() {
+        let mut rng = OsRng;
+        let points: Vec<AffinePoint> = (0..50).map(|_| AffinePoint::random(&mut rng)).collect();
+        let mut scalars: Vec<Scalar> = (0..50).map(|_| Scalar::random(&mut rng)).collect();
+        scalars[0] = Scalar::zero();
+        scalars[1] = -Scalar::one();
+
+        let expected = points
+            .iter()
+            .zip(&scalars)
+            .fold(ProjectivePoint::identity(), |acc, (p, s)| {
+                acc + ProjectivePoint::from(p) * s
+            });
+
+        let scalars: Vec<[u8; 32]> = scalars.iter().map(|s| s.to_bytes()).collect();
+        for c in 2..=10 {
+            assert_eq!(multiply_many_vartime(&points, &scalars, c), expected);
+        }
+    }
+
+    #[test]
+    fn test_multiply_many_non_canonical() {
+        let mut rng = OsRng;
+        let points: Vec<AffinePoint> = (0..10).map(|_| AffinePoint::random(&mut rng)).collect();
+
+        // 2^256 - 1 is not a canonical scalar, and its top window
+        // digit reaches 2^c whenever c divides 256.
+        let scalars = vec![[0xff; 32]; points.len()];
+        // Compute (2^256 - 1).p over the integers, as the points
+        // are not necessarily in the prime-order subgroup.
+        let expected = points.iter().fold(ProjectivePoint::identity(), |acc, p| {
+            let p = ProjectivePoint::from(p);
+            acc + p.double_multi(256) - p
+        });
+
+        for c in [2, 3, 4, 8, 16] {
+            assert_eq!(multiply_many_vartime(&points, &scalars, c), expected);
+        }
+    }
+}
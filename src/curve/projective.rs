@@ -18,20 +18,32 @@ use core::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
+use super::pippenger;
+use super::torsion::{is_torsion_free_with_table, COFACTOR, FQ_MODULUS_BYTES};
 use super::{mul_by_3b, B};
 
 use crate::{AffinePoint, CompressedPoint, UncompressedPoint};
-use crate::{Fp, Fp6, Scalar};
+use crate::{Fp6, Scalar};
 
 use crate::{MINUS_SHIFT_POINT_ARRAY, SHIFT_POINT_PROJECTIVE};
 
 use crate::constants::ODD_MULTIPLES_BASEPOINT;
+use crate::BasePointTable;
 use crate::LookupTable;
 use crate::NafLookupTable;
 
+use crate::recoding;
+#[cfg(feature = "parallel")]
+use crate::utils::{
+    parallel_chunk_size, MIN_PARALLEL_BATCH_NORMALIZE_CHUNK_SIZE, MIN_PARALLEL_MSM_CHUNK_SIZE,
+};
+
+use alloc::vec;
 use alloc::vec::Vec;
-use group::{Curve, Group};
+use group::{Curve, Group, GroupEncoding, UncompressedEncoding, WnafGroup};
 use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "serialize")]
@@ -256,22 +268,22 @@ impl ProjectivePoint {
     /// for more.
     pub const fn generator() -> ProjectivePoint {
         ProjectivePoint {
-            x: Fp6 {
-                c0: Fp(0x263a588f4b0118a1),
-                c1: Fp(0x7757a0bcb26a142d),
-                c2: Fp(0x9215adfc1e925890),
-                c3: Fp(0x430aad2ce14759a4),
-                c4: Fp(0x534ece54de4b2c8),
-                c5: Fp(0xb39050f01f7b1f33),
-            },
-            y: Fp6 {
-                c0: Fp(0xd57f0d0d47482534),
-                c1: Fp(0x26821d894fa8ea0f),
-                c2: Fp(0xc77f564783ef13a1),
-                c3: Fp(0x949c360784284ec2),
-                c4: Fp(0xb7040bd639ef3cc4),
-                c5: Fp(0x8aa635f2719d255f),
-            },
+            x: fp6!(
+                0x263a588f4b0118a1,
+                0x7757a0bcb26a142d,
+                0x9215adfc1e925890,
+                0x430aad2ce14759a4,
+                0x534ece54de4b2c8,
+                0xb39050f01f7b1f33,
+            ),
+            y: fp6!(
+                0xd57f0d0d47482534,
+                0x26821d894fa8ea0f,
+                0xc77f564783ef13a1,
+                0x949c360784284ec2,
+                0xb7040bd639ef3cc4,
+                0x8aa635f2719d255f,
+            ),
             z: Fp6::one(),
         }
     }
@@ -612,7 +624,7 @@ impl ProjectivePoint {
     /// given as byte representation of a `Scalar` element
     pub fn multiply(&self, by: &[u8; 32]) -> ProjectivePoint {
         let table = LookupTable::<16>::from(self);
-        let digits = Scalar::bytes_to_radix_16(by);
+        let digits = recoding::to_signed_window_16(by);
 
         let mut acc = *SHIFT_POINT_PROJECTIVE;
         for i in (0..64).rev() {
@@ -630,7 +642,7 @@ impl ProjectivePoint {
     /// to the scalar.** If the scalar is fixed,
     /// this operation is effectively constant time.
     pub fn multiply_vartime(&self, by: &[u8; 32]) -> ProjectivePoint {
-        let digits = Scalar::bytes_to_wnaf_vartime(by, 5);
+        let digits = recoding::to_wnaf_vartime(by, 5);
 
         // We skip unset digits
         let mut i: usize = 255;
@@ -670,8 +682,8 @@ impl ProjectivePoint {
     ) -> ProjectivePoint {
         let table_lhs = LookupTable::<16>::from(self);
         let table_rhs = LookupTable::<16>::from(rhs);
-        let digits_lhs = Scalar::bytes_to_radix_16(by_lhs);
-        let digits_rhs = Scalar::bytes_to_radix_16(by_rhs);
+        let digits_lhs = recoding::to_signed_window_16(by_lhs);
+        let digits_rhs = recoding::to_signed_window_16(by_rhs);
 
         let mut acc = *SHIFT_POINT_PROJECTIVE;
         for i in (0..64).rev() {
@@ -695,8 +707,8 @@ impl ProjectivePoint {
         by_lhs: &[u8; 32],
         by_rhs: &[u8; 32],
     ) -> ProjectivePoint {
-        let by_lhs_digits = Scalar::bytes_to_wnaf_vartime(by_lhs, 5);
-        let by_rhs_digits = Scalar::bytes_to_wnaf_vartime(by_rhs, 5);
+        let by_lhs_digits = recoding::to_wnaf_vartime(by_lhs, 5);
+        let by_rhs_digits = recoding::to_wnaf_vartime(by_rhs, 5);
 
         // We skip unset digits
         let mut i: usize = 255;
@@ -757,77 +769,163 @@ impl ProjectivePoint {
         by_self: &[u8; 32],
         by_basepoint: &[u8; 32],
     ) -> ProjectivePoint {
-        let by_self_digits = Scalar::bytes_to_wnaf_vartime(by_self, 5);
-        let by_basepoint_digits = Scalar::bytes_to_wnaf_vartime(by_basepoint, 8);
+        Self::multiply_many_with_tables_vartime(
+            &[],
+            &[],
+            &[&*ODD_MULTIPLES_BASEPOINT],
+            &[*by_basepoint],
+            &[*self],
+            &[*by_self],
+        )
+    }
+
+    /// Performs the multiscalar multiplication ∑ a[i].T[i] + ∑ b[i].N[i] + ∑ s[i].p[i],
+    /// where the T[i] are the basepoints of the given `BasePointTable`s, the N[i] are
+    /// the basepoints of the given `NafLookupTable`s, and the p[i] are arbitrary points,
+    /// with all the scalars given as byte representations of `Scalar` elements.
+    ///
+    /// All terms share a single doubling chain, the precomputed tables saving
+    /// the cost of building lookup tables for their associated points.
+    ///
+    /// This operation is useful to speed-up verification of equations such as
+    /// s[0].G + ∑ s[i].p[i] where the point G has some precomputed table.
+    ///
+    /// **This operation is variable time with respect
+    /// to the scalars.** If the scalars are fixed,
+    /// this operation is effectively constant time.
+    ///
+    /// # Panics
+    /// Panics if any list of points has a different length than its associated
+    /// list of scalars, or if `N` is not a power of two smaller than 64.
+    pub fn multiply_many_with_tables_vartime<const N: usize>(
+        basepoint_tables: &[&BasePointTable],
+        basepoint_table_scalars: &[[u8; 32]],
+        naf_tables: &[&NafLookupTable<N>],
+        naf_table_scalars: &[[u8; 32]],
+        points: &[ProjectivePoint],
+        scalars: &[[u8; 32]],
+    ) -> ProjectivePoint {
+        assert_eq!(basepoint_tables.len(), basepoint_table_scalars.len());
+        assert_eq!(naf_tables.len(), naf_table_scalars.len());
+        assert_eq!(points.len(), scalars.len());
+        assert!(N.is_power_of_two() && N <= 64);
 
-        // We skip unset digits
-        let mut i: usize = 255;
-        for j in (0..256).rev() {
-            if by_self_digits[i] != 0 || by_basepoint_digits[i] != 0 {
-                i = j;
-                break;
+        // A NafLookupTable of N elements handles odd digits up to 2N - 1.
+        let naf_width = N.trailing_zeros() as usize + 2;
+
+        let basepoint_table_digits: Vec<[i8; 64]> = basepoint_table_scalars
+            .iter()
+            .map(recoding::to_signed_window_16_vartime)
+            .collect();
+        let naf_table_digits: Vec<[i8; 256]> = naf_table_scalars
+            .iter()
+            .map(|s| recoding::to_wnaf_vartime(s, naf_width))
+            .collect();
+        let point_digits: Vec<[i8; 256]> = scalars
+            .iter()
+            .map(|s| recoding::to_wnaf_vartime(s, 5))
+            .collect();
+        let point_tables: Vec<NafLookupTable<8>> =
+            points.iter().map(NafLookupTable::<8>::from).collect();
+
+        // We skip unset digits, though the doubling chain must reach the
+        // index 4 at which odd windows of the basepoint tables are added.
+        let mut i: usize = if basepoint_tables.is_empty() { 0 } else { 4 };
+        for digits in naf_table_digits.iter().chain(point_digits.iter()) {
+            if let Some(j) = digits.iter().rposition(|&d| d != 0) {
+                i = i.max(j);
             }
         }
-        let table_self = NafLookupTable::<8>::from(self);
-        let table_basepoint = &ODD_MULTIPLES_BASEPOINT;
+
         let mut acc = *SHIFT_POINT_PROJECTIVE;
 
         for j in (0..i + 1).rev() {
             acc = acc.double_unchecked();
 
-            match by_self_digits[j].cmp(&0) {
-                Ordering::Greater => {
-                    acc = acc.add_mixed_unchecked(&table_self.get_point(by_self_digits[j] as usize))
-                }
-                Ordering::Less => {
-                    acc = acc.add_mixed_unchecked(
-                        &table_self.get_point(-by_self_digits[j] as usize).neg(),
-                    )
-                }
-                Ordering::Equal => (),
-            };
+            let naf_terms = naf_tables
+                .iter()
+                .map(|&table| table.0.as_slice())
+                .zip(naf_table_digits.iter())
+                .chain(
+                    point_tables
+                        .iter()
+                        .map(|table| table.0.as_slice())
+                        .zip(point_digits.iter()),
+                );
+            for (table, digits) in naf_terms {
+                match digits[j].cmp(&0) {
+                    Ordering::Greater => {
+                        acc = acc.add_mixed_unchecked(&table[digits[j] as usize / 2])
+                    }
+                    Ordering::Less => {
+                        acc = acc.add_mixed_unchecked(&table[-digits[j] as usize / 2].neg())
+                    }
+                    Ordering::Equal => (),
+                };
+            }
 
-            match by_basepoint_digits[j].cmp(&0) {
-                Ordering::Greater => {
-                    acc = acc.add_mixed_unchecked(
-                        &table_basepoint.get_point(by_basepoint_digits[j] as usize),
-                    )
-                }
-                Ordering::Less => {
-                    acc = acc.add_mixed_unchecked(
-                        &table_basepoint
-                            .get_point(-by_basepoint_digits[j] as usize)
-                            .neg(),
-                    )
+            // Basepoint tables contain multiples of 256^k.T for each window k,
+            // hence odd windows are added 4 doublings before even ones.
+            if j == 4 || j == 0 {
+                let parity = (j == 4) as usize;
+                for (table, digits) in basepoint_tables.iter().zip(basepoint_table_digits.iter()) {
+                    for k in (parity..64).step_by(2).filter(|&k| digits[k] != 0) {
+                        acc = acc.add_mixed_unchecked(&table.0[k / 2].get_point_vartime(digits[k]));
+                    }
                 }
-                Ordering::Equal => (),
-            };
+            }
         }
 
         acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[i + 1])
     }
 
-    /// Performs the jacobian multiscalar multiplication ∑ s[i].p[i] with
+    /// Performs the projective multiscalar multiplication ∑ s[i].p[i] with
     /// the s[i] given as byte representations of `Scalar` elements.
-    // TODO: this is significantly slower than its vartime counterpart, because of the
-    // construction of LookupTable<16> vs NafLookupTable<5>, which is about twice slower.
-    // It would be nice to implement a constant-time variation of the latter, including the
-    // wnaf conversion of the scalar.
     pub fn multiply_many(points: &[ProjectivePoint], scalars: &[[u8; 32]]) -> ProjectivePoint {
-        let digits: Vec<[i8; 64]> = scalars.iter().map(Scalar::bytes_to_radix_16).collect();
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = parallel_chunk_size(points.len(), MIN_PARALLEL_MSM_CHUNK_SIZE);
+            points
+                .par_chunks(chunk_size)
+                .zip(scalars.par_chunks(chunk_size))
+                .map(|(points, scalars)| Self::multiply_many_serial(points, scalars))
+                .reduce(ProjectivePoint::identity, |a, b| a + b)
+        }
 
-        let tables: Vec<LookupTable<16>> = points.iter().map(LookupTable::<16>::from).collect();
+        #[cfg(not(feature = "parallel"))]
+        Self::multiply_many_serial(points, scalars)
+    }
+
+    /// Sequential version of `multiply_many`, also used to process
+    /// each chunk of points when the `parallel` feature is enabled.
+    fn multiply_many_serial(points: &[ProjectivePoint], scalars: &[[u8; 32]]) -> ProjectivePoint {
+        // Even scalars are incremented by one to be recoded with odd digits,
+        // which is corrected at the end by subtracting the associated point.
+        let (digits, is_even): (Vec<[i8; 64]>, Vec<Choice>) = scalars
+            .iter()
+            .map(recoding::to_signed_odd_window_16)
+            .unzip();
+
+        let tables: Vec<NafLookupTable<8>> = points.iter().map(NafLookupTable::<8>::from).collect();
 
         let mut acc = *SHIFT_POINT_PROJECTIVE;
 
         for i in (0..64).rev() {
             acc = acc.double_multi_unchecked(4);
             for (table, digit) in tables.iter().zip(&digits) {
-                acc = acc.add_mixed_unchecked(&table.get_point(digit[i]));
+                acc = acc.add_mixed_unchecked(&table.get_signed_point(digit[i]));
             }
         }
 
-        acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[256])
+        acc = acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[256]);
+
+        for (table, &is_even) in tables.iter().zip(&is_even) {
+            let correction =
+                AffinePoint::conditional_select(&AffinePoint::identity(), &-table.0[0], is_even);
+            acc = acc.add_mixed(&correction);
+        }
+
+        acc
     }
 
     /// Performs the projective multiscalar multiplication ∑ s[i].p[i] with
@@ -839,10 +937,30 @@ impl ProjectivePoint {
     pub fn multiply_many_vartime(
         points: &[ProjectivePoint],
         scalars: &[[u8; 32]],
+    ) -> ProjectivePoint {
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = parallel_chunk_size(points.len(), MIN_PARALLEL_MSM_CHUNK_SIZE);
+            points
+                .par_chunks(chunk_size)
+                .zip(scalars.par_chunks(chunk_size))
+                .map(|(points, scalars)| Self::multiply_many_vartime_serial(points, scalars))
+                .reduce(ProjectivePoint::identity, |a, b| a + b)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        Self::multiply_many_vartime_serial(points, scalars)
+    }
+
+    /// Sequential version of `multiply_many_vartime`, also used to process
+    /// each chunk of points when the `parallel` feature is enabled.
+    fn multiply_many_vartime_serial(
+        points: &[ProjectivePoint],
+        scalars: &[[u8; 32]],
     ) -> ProjectivePoint {
         let digits: Vec<[i8; 256]> = scalars
             .iter()
-            .map(|s| Scalar::bytes_to_wnaf_vartime(s, 5))
+            .map(|s| recoding::to_wnaf_vartime(s, 5))
             .collect();
 
         let tables: Vec<NafLookupTable<8>> = points.iter().map(NafLookupTable::<8>::from).collect();
@@ -867,27 +985,48 @@ impl ProjectivePoint {
         acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[256])
     }
 
+    /// Performs the projective multiscalar multiplication ∑ s[i].p[i] with
+    /// the s[i] given as byte representations of `Scalar` elements, using
+    /// Pippenger's bucket method with a window width depending on the number
+    /// of points. This scales better than `multiply_many_vartime` for large
+    /// batches of points.
+    ///
+    /// **This operation is variable time with respect
+    /// to the scalars.** If the scalars are fixed,
+    /// this operation is effectively constant time.
+    pub fn multiply_many_pippenger_vartime(
+        points: &[ProjectivePoint],
+        scalars: &[[u8; 32]],
+    ) -> ProjectivePoint {
+        let mut points_affine = vec![AffinePoint::identity(); points.len()];
+        ProjectivePoint::batch_normalize(points, &mut points_affine);
+
+        pippenger::multiply_many_vartime(
+            &points_affine,
+            scalars,
+            pippenger::optimal_window_width(points.len()),
+        )
+    }
+
     /// Multiplies by the curve cofactor
     pub fn clear_cofactor(&self) -> ProjectivePoint {
-        // cofactor = 708537115134665106932687062569690615370
-        //          = 0x2150b48e071ef610049bc3f5d54304e4a
-        const COFACTOR_BYTES: [u8; 32] = [
-            74, 78, 48, 84, 93, 63, 188, 73, 0, 97, 239, 113, 224, 72, 11, 21, 2, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-
-        self.multiply_vartime(&COFACTOR_BYTES)
+        self.multiply_vartime(&COFACTOR)
     }
 
     /// Returns true if this point is free of an $h$-torsion component.
     /// This should always return true unless an "unchecked" API was used.
     pub fn is_torsion_free(&self) -> Choice {
-        const FQ_MODULUS_BYTES: [u8; 32] = [
-            207, 172, 212, 174, 62, 98, 67, 212, 34, 119, 21, 48, 35, 167, 122, 50, 181, 55, 10,
-            153, 15, 191, 63, 86, 208, 34, 63, 59, 155, 89, 242, 122,
-        ];
+        // Multiply the point by q and check if it is the identity
+        is_torsion_free_with_table(&NafLookupTable::<8>::from(self))
+    }
 
-        // Clear the r-torsion from the point and check if it is the identity
+    /// Returns true if this point is free of an $h$-torsion component,
+    /// by multiplying it by q with a generic scalar multiplication.
+    /// This is slower than `is_torsion_free`, and mostly serves as a
+    /// reference for it.
+    ///
+    /// **This operation is variable time with respect to the point.**
+    pub fn is_torsion_free_vartime(&self) -> Choice {
         self.multiply_vartime(&FQ_MODULUS_BYTES).is_identity()
     }
 
@@ -896,6 +1035,21 @@ impl ProjectivePoint {
     pub fn batch_normalize(p: &[Self], q: &mut [AffinePoint]) {
         assert_eq!(p.len(), q.len());
 
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = parallel_chunk_size(p.len(), MIN_PARALLEL_BATCH_NORMALIZE_CHUNK_SIZE);
+            p.par_chunks(chunk_size)
+                .zip(q.par_chunks_mut(chunk_size))
+                .for_each(|(p, q)| Self::batch_normalize_serial(p, q));
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        Self::batch_normalize_serial(p, q)
+    }
+
+    /// Sequential version of `batch_normalize`, also used to process
+    /// each chunk of points when the `parallel` feature is enabled.
+    fn batch_normalize_serial(p: &[Self], q: &mut [AffinePoint]) {
         let mut acc = Fp6::one();
         for (p, q) in p.iter().zip(q.iter_mut()) {
             // We use the `x` field of `AffinePoint` to store the product
@@ -985,6 +1139,63 @@ impl Curve for ProjectivePoint {
     }
 }
 
+impl GroupEncoding for ProjectivePoint {
+    type Repr = CompressedPoint;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_compressed(bytes)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        AffinePoint::from_compressed_unchecked(bytes).map(ProjectivePoint::from)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.to_compressed()
+    }
+}
+
+impl UncompressedEncoding for ProjectivePoint {
+    type Uncompressed = UncompressedPoint;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed_unchecked(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        self.to_uncompressed()
+    }
+}
+
+impl WnafGroup for ProjectivePoint {
+    fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        // Thresholds on the number of scalars above which
+        // incrementing the window width becomes beneficial.
+        // These are taken from the `WnafGroup` implementation of the
+        // `bls12_381` crate, where they were obtained by benchmarking.
+        // They have not been re-measured for this curve, but mostly depend
+        // on the ratio between the table size and the number of non-zero
+        // digits of ~255-bit scalars, which is the same for both curves.
+        const RECOMMENDATIONS: [usize; 12] =
+            [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
 // SERDE SERIALIZATION
 // ================================================================================================
 
@@ -1017,9 +1228,11 @@ impl<'de> Deserialize<'de> for ProjectivePoint {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::curve::non_torsion_free_point;
+    use crate::Fp;
     use rand_core::OsRng;
 
-    use crate::{BasePointTable, BASEPOINT_TABLE};
+    use crate::BASEPOINT_TABLE;
 
     #[test]
     fn test_is_on_curve() {
@@ -1349,6 +1562,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_projective_basepoint_table_vartime_edge_cases() {
+        let g = ProjectivePoint::generator();
+
+        // Scalars with many zero digits
+        let mut scalars = vec![
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::from(16u8),
+            Scalar::from(0x8000_0000_0000_0000u64),
+        ];
+        let mut bytes = [0u8; 32];
+        bytes[17] = 0x40;
+        scalars.push(Scalar::from_bytes(&bytes).unwrap());
+
+        for c in scalars {
+            assert_eq!(g * c, BASEPOINT_TABLE.multiply_vartime(&c.to_bytes()));
+        }
+    }
+
     #[test]
     fn test_projective_double_scalar_multiplication() {
         let mut rng = OsRng;
@@ -1378,6 +1612,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_projective_multiply_many_pippenger() {
+        let mut rng = OsRng;
+
+        for n in [0, 1, 2, 33, 100] {
+            let points: Vec<ProjectivePoint> =
+                (0..n).map(|_| ProjectivePoint::random(&mut rng)).collect();
+            let mut scalars: Vec<[u8; 32]> = (0..n)
+                .map(|_| Scalar::random(&mut rng).to_bytes())
+                .collect();
+            if n > 1 {
+                scalars[0] = Scalar::zero().to_bytes();
+                scalars[1] = (-Scalar::one()).to_bytes();
+            }
+
+            assert_eq!(
+                ProjectivePoint::multiply_many_pippenger_vartime(&points, &scalars),
+                ProjectivePoint::multiply_many_vartime(&points, &scalars)
+            );
+        }
+    }
+
+    #[test]
+    fn test_projective_multiply_many_edge_cases() {
+        let mut rng = OsRng;
+        let g = ProjectivePoint::random(&mut rng);
+        let h = ProjectivePoint::random(&mut rng);
+        let scalars = [
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::from(2u8),
+            -Scalar::one(),
+            -Scalar::from(2u8),
+        ];
+
+        for a in scalars {
+            for b in scalars {
+                let expected = g * a + h * b;
+                let bytes = [a.to_bytes(), b.to_bytes()];
+
+                assert_eq!(ProjectivePoint::multiply_many(&[g, h], &bytes), expected);
+                assert_eq!(
+                    ProjectivePoint::multiply_many_vartime(&[g, h], &bytes),
+                    expected
+                );
+                assert_eq!(
+                    ProjectivePoint::multiply_many(&[g, ProjectivePoint::identity()], &bytes),
+                    g * a
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_projective_double_scalar_multiplication_with_basepoint() {
         let mut rng = OsRng;
@@ -1395,6 +1682,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_projective_multiply_many_with_tables() {
+        let mut rng = OsRng;
+        let basepoints: Vec<ProjectivePoint> =
+            (0..3).map(|_| ProjectivePoint::random(&mut rng)).collect();
+        let basepoint_tables: Vec<BasePointTable> = basepoints
+            .iter()
+            .map(|p| BasePointTable::from(AffinePoint::from(p)))
+            .collect();
+        let basepoint_tables: Vec<&BasePointTable> = basepoint_tables.iter().collect();
+        let naf_points: Vec<ProjectivePoint> =
+            (0..3).map(|_| ProjectivePoint::random(&mut rng)).collect();
+        let naf_tables: Vec<NafLookupTable<16>> =
+            naf_points.iter().map(NafLookupTable::<16>::from).collect();
+        let naf_tables: Vec<&NafLookupTable<16>> = naf_tables.iter().collect();
+        let mut points: Vec<ProjectivePoint> =
+            (0..4).map(|_| ProjectivePoint::random(&mut rng)).collect();
+        points[3] = ProjectivePoint::identity();
+
+        let naive = |points: &[ProjectivePoint], scalars: &[Scalar]| {
+            points
+                .iter()
+                .zip(scalars)
+                .fold(ProjectivePoint::identity(), |acc, (p, s)| acc + p * s)
+        };
+        let to_bytes = |scalars: &[Scalar]| -> Vec<[u8; 32]> {
+            scalars.iter().map(|s| s.to_bytes()).collect()
+        };
+
+        for _ in 0..10 {
+            let mut a: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+            let b: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut rng)).collect();
+            let mut s: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+            a[0] = Scalar::zero();
+            s[0] = -Scalar::one();
+
+            assert_eq!(
+                ProjectivePoint::multiply_many_with_tables_vartime(
+                    &basepoint_tables,
+                    &to_bytes(&a),
+                    &naf_tables,
+                    &to_bytes(&b),
+                    &points,
+                    &to_bytes(&s),
+                ),
+                naive(&basepoints, &a) + naive(&naf_points, &b) + naive(&points, &s)
+            );
+        }
+
+        // Only small scalars with basepoint tables
+        let a = [Scalar::from(3u8), Scalar::from(17u8), Scalar::zero()];
+        assert_eq!(
+            ProjectivePoint::multiply_many_with_tables_vartime::<8>(
+                &basepoint_tables,
+                &to_bytes(&a),
+                &[],
+                &[],
+                &[],
+                &[],
+            ),
+            naive(&basepoints, &a)
+        );
+
+        // Empty inputs and zero scalars
+        assert!(bool::from(
+            ProjectivePoint::multiply_many_with_tables_vartime::<8>(&[], &[], &[], &[], &[], &[])
+                .is_identity()
+        ));
+        assert!(bool::from(
+            ProjectivePoint::multiply_many_with_tables_vartime(
+                &basepoint_tables,
+                &[[0u8; 32]; 3],
+                &naf_tables,
+                &[[0u8; 32]; 3],
+                &points,
+                &[[0u8; 32]; 4],
+            )
+            .is_identity()
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_projective_multiply_many_with_tables_invalid_length() {
+        let _ = ProjectivePoint::multiply_many_with_tables_vartime::<8>(
+            &[&BASEPOINT_TABLE],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+        );
+    }
+
     #[test]
     fn test_clear_cofactor() {
         // the generator (and the identity) are always on the curve
@@ -1403,25 +1784,7 @@ mod tests {
         let id = ProjectivePoint::identity();
         assert!(bool::from(id.clear_cofactor().is_on_curve()));
 
-        let point = ProjectivePoint {
-            x: Fp6 {
-                c0: Fp(0x9bfcd3244afcb637),
-                c1: Fp(0x39005e478830b187),
-                c2: Fp(0x7046f1c03b42c6cc),
-                c3: Fp(0xb5eeac99193711e5),
-                c4: Fp(0x7fd272e724307b98),
-                c5: Fp(0xcc371dd6dd5d8625),
-            },
-            y: Fp6 {
-                c0: Fp(0x9d03fdc216dfaae8),
-                c1: Fp(0xbf4ade2a7665d9b8),
-                c2: Fp(0xf08b022d5b3262b7),
-                c3: Fp(0x2eaf583a3cf15c6f),
-                c4: Fp(0xa92531e4b1338285),
-                c5: Fp(0x5b8157814141a7a7),
-            },
-            z: Fp6::one(),
-        };
+        let point = ProjectivePoint::from(non_torsion_free_point());
 
         assert!(bool::from(point.is_on_curve()));
         assert!(!bool::from(AffinePoint::from(point).is_torsion_free()));
@@ -1434,25 +1797,7 @@ mod tests {
 
     #[test]
     fn test_is_torsion_free() {
-        let a = AffinePoint {
-            x: Fp6 {
-                c0: Fp(0x9bfcd3244afcb637),
-                c1: Fp(0x39005e478830b187),
-                c2: Fp(0x7046f1c03b42c6cc),
-                c3: Fp(0xb5eeac99193711e5),
-                c4: Fp(0x7fd272e724307b98),
-                c5: Fp(0xcc371dd6dd5d8625),
-            },
-            y: Fp6 {
-                c0: Fp(0x9d03fdc216dfaae8),
-                c1: Fp(0xbf4ade2a7665d9b8),
-                c2: Fp(0xf08b022d5b3262b7),
-                c3: Fp(0x2eaf583a3cf15c6f),
-                c4: Fp(0xa92531e4b1338285),
-                c5: Fp(0x5b8157814141a7a7),
-            },
-            infinity: Choice::from(0u8),
-        };
+        let a = non_torsion_free_point();
 
         let a = ProjectivePoint::from(&a);
         assert!(bool::from(a.is_on_curve()));
@@ -1461,6 +1806,35 @@ mod tests {
         assert!(bool::from(ProjectivePoint::generator().is_torsion_free()));
     }
 
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_projective_parallel_matches_serial() {
+        let mut rng = OsRng;
+
+        for n in [0, 1, 17, 100, 300] {
+            let points: Vec<ProjectivePoint> =
+                (0..n).map(|_| ProjectivePoint::random(&mut rng)).collect();
+            let scalars: Vec<[u8; 32]> = (0..n)
+                .map(|_| Scalar::random(&mut rng).to_bytes())
+                .collect();
+
+            assert_eq!(
+                ProjectivePoint::multiply_many(&points, &scalars),
+                ProjectivePoint::multiply_many_serial(&points, &scalars)
+            );
+            assert_eq!(
+                ProjectivePoint::multiply_many_vartime(&points, &scalars),
+                ProjectivePoint::multiply_many_vartime_serial(&points, &scalars)
+            );
+
+            let mut normalized = vec![AffinePoint::identity(); n];
+            let mut normalized_serial = vec![AffinePoint::identity(); n];
+            ProjectivePoint::batch_normalize(&points, &mut normalized);
+            ProjectivePoint::batch_normalize_serial(&points, &mut normalized_serial);
+            assert_eq!(normalized, normalized_serial);
+        }
+    }
+
     #[test]
     fn test_batch_normalize() {
         let a = ProjectivePoint::generator().double();
@@ -1621,6 +1995,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_group_encoding() {
+        let mut rng = OsRng;
+        let mut points = vec![ProjectivePoint::identity(), ProjectivePoint::generator()];
+        for _ in 0..10 {
+            points.push(ProjectivePoint::random(&mut rng));
+        }
+
+        for p in points {
+            let bytes = GroupEncoding::to_bytes(&p);
+            assert_eq!(bytes, p.to_compressed());
+            assert_eq!(
+                <ProjectivePoint as GroupEncoding>::from_bytes(&bytes).unwrap(),
+                p
+            );
+            assert_eq!(
+                <ProjectivePoint as GroupEncoding>::from_bytes_unchecked(&bytes).unwrap(),
+                p
+            );
+
+            let bytes = UncompressedEncoding::to_uncompressed(&p);
+            assert_eq!(bytes, p.to_uncompressed());
+            assert_eq!(
+                <ProjectivePoint as UncompressedEncoding>::from_uncompressed(&bytes).unwrap(),
+                p
+            );
+            assert_eq!(
+                <ProjectivePoint as UncompressedEncoding>::from_uncompressed_unchecked(&bytes)
+                    .unwrap(),
+                p
+            );
+        }
+
+        // Points outside of the prime-order subgroup are
+        // only accepted by the unchecked variants.
+        let a = non_torsion_free_point();
+        let a = ProjectivePoint::from(&a);
+
+        let bytes = GroupEncoding::to_bytes(&a);
+        assert!(bool::from(
+            <ProjectivePoint as GroupEncoding>::from_bytes(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            <ProjectivePoint as GroupEncoding>::from_bytes_unchecked(&bytes).is_some()
+        ));
+
+        let bytes = UncompressedEncoding::to_uncompressed(&a);
+        assert!(bool::from(
+            <ProjectivePoint as UncompressedEncoding>::from_uncompressed(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            <ProjectivePoint as UncompressedEncoding>::from_uncompressed_unchecked(&bytes)
+                .is_some()
+        ));
+    }
+
+    #[test]
+    fn test_wnaf() {
+        let mut rng = OsRng;
+
+        assert_eq!(ProjectivePoint::recommended_wnaf_for_num_scalars(1), 4);
+        assert_eq!(ProjectivePoint::recommended_wnaf_for_num_scalars(2), 5);
+        assert_eq!(
+            ProjectivePoint::recommended_wnaf_for_num_scalars(usize::MAX),
+            16
+        );
+
+        for w in 2..14 {
+            for _ in 0..5 {
+                let g = ProjectivePoint::random(&mut rng);
+                let s = Scalar::random(&mut rng);
+                let expected = g * s;
+
+                assert_eq!(group::Wnaf::new().base(g, 1).scalar(&s), expected);
+
+                let mut wnaf = group::Wnaf::new();
+                assert_eq!(wnaf.scalar(&s).base(g), expected);
+                assert_eq!(wnaf.base(g, w).scalar(&s), expected);
+            }
+        }
+
+        // Shared bases and scalars
+        let g = ProjectivePoint::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+        let mut wnaf_base = group::Wnaf::new();
+        let wnaf_base = wnaf_base.base(g, 10);
+        let mut wnaf_scalar = group::Wnaf::new();
+        let wnaf_scalar = wnaf_scalar.scalar(&s);
+        for _ in 0..10 {
+            let h = ProjectivePoint::random(&mut rng);
+            let t = Scalar::random(&mut rng);
+            assert_eq!(wnaf_base.shared().scalar(&t), g * t);
+            assert_eq!(wnaf_scalar.shared().base(h), h * s);
+        }
+    }
+
     // SERDE SERIALIZATIOIN
     // ================================================================================================
 
@@ -0,0 +1,935 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides point types restricted to the prime-order
+//! subgroup of the cheetah curve, in projective and affine coordinates.
+//!
+//! The traits of the `group` crate are split between these types and
+//! the full-curve ones as follows:
+//!
+//! - `SubgroupPoint` implements `PrimeGroup` and `PrimeCurve`, with
+//!   `SubgroupAffinePoint` as `PrimeCurveAffine` representation. Only
+//!   these types are guaranteed to lie in the prime-order subgroup.
+//! - `ProjectivePoint` and `JacobianPoint` both implement `CofactorGroup`,
+//!   with `SubgroupPoint` as subgroup.
+//! - `JacobianPoint` alone implements `CofactorCurve`, with `AffinePoint`
+//!   as `CofactorCurveAffine` representation. The trait requires the
+//!   product of an affine point by a scalar to be of the curve type, and
+//!   `AffinePoint * Scalar` outputs a `JacobianPoint`. As an affine type
+//!   can only be bound to a single curve type, `ProjectivePoint` cannot
+//!   implement `CofactorCurve` as well.
+//!
+//! `AffinePoint` hence implements `CofactorCurveAffine` but not
+//! `PrimeCurveAffine`, which is reserved to `SubgroupAffinePoint` as it
+//! would wrongly assert that any affine point has prime order.
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::Scalar;
+use crate::{AffinePoint, JacobianPoint, ProjectivePoint};
+use crate::{CompressedPoint, UncompressedPoint};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use group::{
+    cofactor::{CofactorCurve, CofactorCurveAffine, CofactorGroup},
+    prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
+    Curve, Group, GroupEncoding, UncompressedEncoding, WnafGroup,
+};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "serialize")]
+use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A point of the prime-order subgroup of the curve, in projective coordinates.
+///
+/// Such a point can only be obtained from torsion-free points,
+/// through `SubgroupPoint::from_point()`, `CofactorGroup::into_subgroup()`,
+/// `CofactorGroup::clear_cofactor()` or the checked decoding methods.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubgroupPoint(pub(crate) ProjectivePoint);
+
+/// A point of the prime-order subgroup of the curve, in affine coordinates.
+///
+/// Such a point can only be obtained from torsion-free points,
+/// through `SubgroupAffinePoint::from_point()`, the conversion
+/// of a `SubgroupPoint` or the checked decoding methods.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubgroupAffinePoint(pub(crate) AffinePoint);
+
+impl zeroize::DefaultIsZeroes for SubgroupPoint {}
+
+impl zeroize::DefaultIsZeroes for SubgroupAffinePoint {}
+
+impl fmt::Display for SubgroupPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for SubgroupAffinePoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Hash for SubgroupPoint {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.hash(hasher);
+    }
+}
+
+impl Hash for SubgroupAffinePoint {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.hash(hasher);
+    }
+}
+
+impl ConstantTimeEq for SubgroupPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeEq for SubgroupAffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for SubgroupPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(ProjectivePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConditionallySelectable for SubgroupAffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(AffinePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<SubgroupPoint> for ProjectivePoint {
+    fn from(p: SubgroupPoint) -> Self {
+        p.0
+    }
+}
+
+impl<'a> From<&'a SubgroupPoint> for ProjectivePoint {
+    fn from(p: &'a SubgroupPoint) -> Self {
+        p.0
+    }
+}
+
+impl From<SubgroupPoint> for JacobianPoint {
+    fn from(p: SubgroupPoint) -> Self {
+        AffinePoint::from(p.0).into()
+    }
+}
+
+impl<'a> From<&'a SubgroupPoint> for JacobianPoint {
+    fn from(p: &'a SubgroupPoint) -> Self {
+        AffinePoint::from(p.0).into()
+    }
+}
+
+impl From<SubgroupPoint> for AffinePoint {
+    fn from(p: SubgroupPoint) -> Self {
+        p.0.into()
+    }
+}
+
+impl<'a> From<&'a SubgroupPoint> for AffinePoint {
+    fn from(p: &'a SubgroupPoint) -> Self {
+        (&p.0).into()
+    }
+}
+
+impl From<SubgroupAffinePoint> for AffinePoint {
+    fn from(p: SubgroupAffinePoint) -> Self {
+        p.0
+    }
+}
+
+impl<'a> From<&'a SubgroupAffinePoint> for AffinePoint {
+    fn from(p: &'a SubgroupAffinePoint) -> Self {
+        p.0
+    }
+}
+
+impl From<SubgroupAffinePoint> for SubgroupPoint {
+    fn from(p: SubgroupAffinePoint) -> Self {
+        Self((&p.0).into())
+    }
+}
+
+impl<'a> From<&'a SubgroupAffinePoint> for SubgroupPoint {
+    fn from(p: &'a SubgroupAffinePoint) -> Self {
+        Self((&p.0).into())
+    }
+}
+
+impl From<SubgroupPoint> for SubgroupAffinePoint {
+    fn from(p: SubgroupPoint) -> Self {
+        Self((&p.0).into())
+    }
+}
+
+impl<'a> From<&'a SubgroupPoint> for SubgroupAffinePoint {
+    fn from(p: &'a SubgroupPoint) -> Self {
+        Self((&p.0).into())
+    }
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+impl Neg for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn neg(self) -> SubgroupPoint {
+        SubgroupPoint(-self.0)
+    }
+}
+
+impl Neg for SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn neg(self) -> SubgroupPoint {
+        -&self
+    }
+}
+
+impl Neg for &SubgroupAffinePoint {
+    type Output = SubgroupAffinePoint;
+
+    #[inline]
+    fn neg(self) -> SubgroupAffinePoint {
+        SubgroupAffinePoint(-self.0)
+    }
+}
+
+impl Neg for SubgroupAffinePoint {
+    type Output = SubgroupAffinePoint;
+
+    #[inline]
+    fn neg(self) -> SubgroupAffinePoint {
+        -&self
+    }
+}
+
+impl Add<&SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn add(self, rhs: &SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 + rhs.0)
+    }
+}
+
+impl Sub<&SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn sub(self, rhs: &SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 - rhs.0)
+    }
+}
+
+impl Add<&SubgroupAffinePoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn add(self, rhs: &SubgroupAffinePoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 + rhs.0)
+    }
+}
+
+impl Sub<&SubgroupAffinePoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn sub(self, rhs: &SubgroupAffinePoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 - rhs.0)
+    }
+}
+
+impl Add<&SubgroupPoint> for &SubgroupAffinePoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn add(self, rhs: &SubgroupPoint) -> SubgroupPoint {
+        rhs + self
+    }
+}
+
+impl Sub<&SubgroupPoint> for &SubgroupAffinePoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn sub(self, rhs: &SubgroupPoint) -> SubgroupPoint {
+        -rhs + self
+    }
+}
+
+impl Add<&SubgroupPoint> for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    #[inline]
+    fn add(self, rhs: &SubgroupPoint) -> ProjectivePoint {
+        self + rhs.0
+    }
+}
+
+impl Sub<&SubgroupPoint> for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    #[inline]
+    fn sub(self, rhs: &SubgroupPoint) -> ProjectivePoint {
+        self - rhs.0
+    }
+}
+
+impl Add<&SubgroupPoint> for &JacobianPoint {
+    type Output = JacobianPoint;
+
+    #[inline]
+    fn add(self, rhs: &SubgroupPoint) -> JacobianPoint {
+        self + AffinePoint::from(rhs)
+    }
+}
+
+impl Sub<&SubgroupPoint> for &JacobianPoint {
+    type Output = JacobianPoint;
+
+    #[inline]
+    fn sub(self, rhs: &SubgroupPoint) -> JacobianPoint {
+        self - AffinePoint::from(rhs)
+    }
+}
+
+impl Mul<&Scalar> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    fn mul(self, other: &Scalar) -> Self::Output {
+        SubgroupPoint(self.0 * other)
+    }
+}
+
+impl Mul<&Scalar> for &SubgroupAffinePoint {
+    type Output = SubgroupPoint;
+
+    fn mul(self, other: &Scalar) -> Self::Output {
+        SubgroupPoint(ProjectivePoint::from(&self.0) * other)
+    }
+}
+
+impl_binops_additive!(SubgroupPoint, SubgroupPoint);
+impl_binops_additive!(SubgroupPoint, SubgroupAffinePoint);
+impl_binops_additive_specify_output!(SubgroupAffinePoint, SubgroupPoint, SubgroupPoint);
+impl_binops_additive!(ProjectivePoint, SubgroupPoint);
+impl_binops_additive!(JacobianPoint, SubgroupPoint);
+impl_binops_multiplicative!(SubgroupPoint, Scalar);
+impl_binops_multiplicative_mixed!(SubgroupAffinePoint, Scalar, SubgroupPoint);
+
+impl<T> Sum<T> for SubgroupPoint
+where
+    T: Borrow<SubgroupPoint>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+    }
+}
+
+// SUBGROUP POINT
+// ================================================================================================
+
+impl SubgroupPoint {
+    /// Returns the identity of the group: the point at infinity.
+    pub const fn identity() -> Self {
+        Self(ProjectivePoint::identity())
+    }
+
+    /// Returns a fixed generator of the prime-order subgroup.
+    pub const fn generator() -> Self {
+        Self(ProjectivePoint::generator())
+    }
+
+    /// Computes a random `SubgroupPoint` element
+    pub fn random(rng: impl RngCore) -> Self {
+        // Random projective points have their cofactor cleared.
+        Self(ProjectivePoint::random(rng))
+    }
+
+    /// Returns the given point if it lies in the prime-order subgroup.
+    pub fn from_point(point: &ProjectivePoint) -> CtOption<Self> {
+        CtOption::new(Self(*point), point.is_torsion_free())
+    }
+
+    /// Returns true if this element is the identity element.
+    pub fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    /// Computes the doubling of this point.
+    pub fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+
+    /// Outputs a compress byte representation of this `SubgroupPoint` element
+    pub fn to_compressed(&self) -> CompressedPoint {
+        self.0.to_compressed()
+    }
+
+    /// Outputs an uncompressed byte representation of this `SubgroupPoint` element
+    pub fn to_uncompressed(&self) -> UncompressedPoint {
+        self.0.to_uncompressed()
+    }
+}
+
+impl SubgroupAffinePoint {
+    /// Returns the identity of the group: the point at infinity.
+    pub fn identity() -> Self {
+        Self(AffinePoint::identity())
+    }
+
+    /// Returns a fixed generator of the prime-order subgroup.
+    pub fn generator() -> Self {
+        Self(AffinePoint::generator())
+    }
+
+    /// Returns the given point if it lies in the prime-order subgroup.
+    pub fn from_point(point: &AffinePoint) -> CtOption<Self> {
+        CtOption::new(Self(*point), point.is_torsion_free())
+    }
+
+    /// Returns true if this element is the identity element.
+    pub fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    /// Outputs a compress byte representation of this `SubgroupAffinePoint` element
+    pub fn to_compressed(&self) -> CompressedPoint {
+        self.0.to_compressed()
+    }
+
+    /// Outputs an uncompressed byte representation of this `SubgroupAffinePoint` element
+    pub fn to_uncompressed(&self) -> UncompressedPoint {
+        self.0.to_uncompressed()
+    }
+}
+
+// GROUP TRAITS IMPLEMENTATION
+// ================================================================================================
+
+impl Group for SubgroupPoint {
+    type Scalar = Scalar;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        Self::random(&mut rng)
+    }
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+}
+
+impl Curve for SubgroupPoint {
+    type AffineRepr = SubgroupAffinePoint;
+
+    fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+        assert_eq!(p.len(), q.len());
+
+        let points: Vec<ProjectivePoint> = p.iter().map(|p| p.0).collect();
+        let mut points_affine = vec![AffinePoint::identity(); p.len()];
+        ProjectivePoint::batch_normalize(&points, &mut points_affine);
+
+        for (q, point) in q.iter_mut().zip(points_affine) {
+            *q = SubgroupAffinePoint(point);
+        }
+    }
+
+    fn to_affine(&self) -> Self::AffineRepr {
+        self.into()
+    }
+}
+
+impl PrimeGroup for SubgroupPoint {}
+
+impl PrimeCurve for SubgroupPoint {
+    type Affine = SubgroupAffinePoint;
+}
+
+impl PrimeCurveAffine for SubgroupAffinePoint {
+    type Scalar = Scalar;
+    type Curve = SubgroupPoint;
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.is_identity()
+    }
+
+    fn to_curve(&self) -> Self::Curve {
+        self.into()
+    }
+}
+
+impl GroupEncoding for SubgroupPoint {
+    type Repr = CompressedPoint;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        // Decompression checks that the point is torsion-free.
+        ProjectivePoint::from_compressed(bytes).map(Self)
+    }
+
+    /// Decodes a point, checking that it lies in the prime-order subgroup.
+    ///
+    /// Unlike `AffinePoint::from_compressed_unchecked`, this check cannot be
+    /// skipped, as it is an invariant of `SubgroupPoint`.
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.to_compressed()
+    }
+}
+
+impl GroupEncoding for SubgroupAffinePoint {
+    type Repr = CompressedPoint;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        // Decompression checks that the point is torsion-free.
+        AffinePoint::from_compressed(bytes).map(Self)
+    }
+
+    /// Decodes a point, checking that it lies in the prime-order subgroup.
+    ///
+    /// Unlike `AffinePoint::from_compressed_unchecked`, this check cannot be
+    /// skipped, as it is an invariant of `SubgroupAffinePoint`.
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.to_compressed()
+    }
+}
+
+impl UncompressedEncoding for SubgroupPoint {
+    type Uncompressed = UncompressedPoint;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        ProjectivePoint::from_uncompressed(bytes).map(Self)
+    }
+
+    /// Decodes a point, checking that it lies in the prime-order subgroup.
+    ///
+    /// Unlike `ProjectivePoint::from_uncompressed_unchecked`, this check cannot
+    /// be skipped, as it is an invariant of `SubgroupPoint`.
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        self.to_uncompressed()
+    }
+}
+
+impl UncompressedEncoding for SubgroupAffinePoint {
+    type Uncompressed = UncompressedPoint;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        AffinePoint::from_uncompressed(bytes).map(Self)
+    }
+
+    /// Decodes a point, checking that it lies in the prime-order subgroup.
+    ///
+    /// Unlike `AffinePoint::from_uncompressed_unchecked`, this check cannot
+    /// be skipped, as it is an invariant of `SubgroupAffinePoint`.
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        Self::from_uncompressed(bytes)
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        self.to_uncompressed()
+    }
+}
+
+impl WnafGroup for SubgroupPoint {
+    fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        ProjectivePoint::recommended_wnaf_for_num_scalars(num_scalars)
+    }
+}
+
+impl CofactorGroup for ProjectivePoint {
+    type Subgroup = SubgroupPoint;
+
+    fn clear_cofactor(&self) -> Self::Subgroup {
+        SubgroupPoint(self.clear_cofactor())
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        SubgroupPoint::from_point(&self)
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        self.is_torsion_free()
+    }
+}
+
+impl CofactorGroup for JacobianPoint {
+    type Subgroup = SubgroupPoint;
+
+    fn clear_cofactor(&self) -> Self::Subgroup {
+        SubgroupPoint(ProjectivePoint::from(AffinePoint::from(
+            self.clear_cofactor(),
+        )))
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        let point = ProjectivePoint::from(AffinePoint::from(self));
+        CtOption::new(SubgroupPoint(point), self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        self.is_torsion_free()
+    }
+}
+
+impl CofactorCurve for JacobianPoint {
+    type Affine = AffinePoint;
+}
+
+impl CofactorCurveAffine for AffinePoint {
+    type Scalar = Scalar;
+    type Curve = JacobianPoint;
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        Self::generator()
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.is_identity()
+    }
+
+    fn to_curve(&self) -> Self::Curve {
+        self.into()
+    }
+}
+
+// SERDE SERIALIZATION
+// ================================================================================================
+
+#[cfg(feature = "serialize")]
+impl Serialize for SubgroupPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_compressed().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for SubgroupPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The deserialization of an `AffinePoint` checks that it is torsion-free.
+        AffinePoint::deserialize(deserializer).map(|p| Self(p.into()))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for SubgroupAffinePoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_compressed().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for SubgroupAffinePoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The deserialization of an `AffinePoint` checks that it is torsion-free.
+        AffinePoint::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::non_torsion_free_point;
+    use group::ff::Field;
+    use rand_core::OsRng;
+
+    // Adapted from the generic tests of the `group` crate.
+    #[allow(clippy::eq_op)]
+    fn prime_curve_tests<G>()
+    where
+        G: PrimeCurve,
+        G::Affine: UncompressedEncoding,
+    {
+        let mut rng = OsRng;
+
+        // Identity edge cases
+        assert!(bool::from(G::identity().neg().is_identity()));
+        assert!(bool::from(G::identity().double().is_identity()));
+        {
+            let r = G::random(&mut rng);
+            assert_eq!(r + G::identity(), r);
+            assert_eq!(r + G::Affine::identity(), r);
+            assert!(bool::from(
+                (G::identity() + G::Affine::identity()).is_identity()
+            ));
+            assert_eq!(G::identity() + r.to_affine(), r);
+        }
+
+        for _ in 0..10 {
+            let a = G::random(&mut rng);
+            let b = G::random(&mut rng);
+            let c = G::random(&mut rng);
+            let s = G::Scalar::random(&mut rng);
+            let t = G::Scalar::random(&mut rng);
+
+            // Transformations
+            assert_eq!(a.to_affine().to_curve(), a);
+            assert_eq!(a.to_affine().to_curve().to_affine().to_curve(), a);
+
+            // Addition
+            assert_eq!(a + b, b + a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!(a + b.to_affine(), a + b);
+            assert_eq!(a - b, a + (-b));
+            assert!(bool::from((a - a).is_identity()));
+
+            // Doubling
+            assert_eq!(a.double(), a + a);
+
+            // Negation
+            assert_eq!(-a.to_affine(), (-a).to_affine());
+            assert!(bool::from((a + (-a)).is_identity()));
+
+            // Multiplication
+            assert_eq!((a * s) * t, a * (s * t));
+            assert_eq!(a.to_affine() * s, a * s);
+            assert_eq!((a + b) * s, a * s + b * s);
+
+            // Encodings
+            let affine = a.to_affine();
+            assert_eq!(G::from_bytes(&a.to_bytes()).unwrap(), a);
+            assert_eq!(G::Affine::from_bytes(&affine.to_bytes()).unwrap(), affine);
+            assert_eq!(
+                G::Affine::from_uncompressed(&affine.to_uncompressed()).unwrap(),
+                affine
+            );
+        }
+
+        // Batch normalization
+        let points: Vec<G> = (0..10).map(|_| G::random(&mut rng)).collect();
+        let mut points_affine = vec![G::Affine::identity(); 10];
+        G::batch_normalize(&points, &mut points_affine);
+        for (p, q) in points.iter().zip(&points_affine) {
+            assert_eq!(p.to_affine(), *q);
+        }
+    }
+
+    #[test]
+    fn test_subgroup_point_group_traits() {
+        prime_curve_tests::<SubgroupPoint>();
+    }
+
+    #[test]
+    fn test_from_point() {
+        let mut rng = OsRng;
+        let p = ProjectivePoint::random(&mut rng);
+        assert_eq!(
+            ProjectivePoint::from(SubgroupPoint::from_point(&p).unwrap()),
+            p
+        );
+        assert_eq!(
+            AffinePoint::from(SubgroupAffinePoint::from_point(&p.into()).unwrap()),
+            AffinePoint::from(p)
+        );
+
+        let a = non_torsion_free_point();
+        assert!(bool::from(a.is_on_curve()));
+        assert!(bool::from(SubgroupAffinePoint::from_point(&a).is_none()));
+        assert!(bool::from(SubgroupPoint::from_point(&a.into()).is_none()));
+    }
+
+    #[test]
+    fn test_cofactor_group() {
+        let mut rng = OsRng;
+        let a = non_torsion_free_point();
+
+        let p = ProjectivePoint::from(a);
+        assert!(!bool::from(CofactorGroup::is_torsion_free(&p)));
+        assert!(!bool::from(p.is_small_order()));
+        assert!(bool::from(p.into_subgroup().is_none()));
+        let cleared = CofactorGroup::clear_cofactor(&p);
+        assert!(bool::from(ProjectivePoint::from(cleared).is_torsion_free()));
+        assert_eq!(ProjectivePoint::from(cleared), p.clear_cofactor());
+
+        let p = JacobianPoint::from(a);
+        assert!(!bool::from(CofactorGroup::is_torsion_free(&p)));
+        assert!(bool::from(p.into_subgroup().is_none()));
+        let cleared = CofactorGroup::clear_cofactor(&p);
+        assert_eq!(JacobianPoint::from(cleared), p.clear_cofactor());
+
+        let p = ProjectivePoint::random(&mut rng);
+        assert_eq!(ProjectivePoint::from(p.into_subgroup().unwrap()), p);
+        let q = JacobianPoint::from(AffinePoint::from(p));
+        assert_eq!(JacobianPoint::from(q.into_subgroup().unwrap()), q);
+
+        assert!(bool::from(ProjectivePoint::identity().is_small_order()));
+        assert!(bool::from(JacobianPoint::identity().is_small_order()));
+
+        // Mixed operations between full-curve and subgroup points
+        let s = SubgroupPoint::random(&mut rng);
+        assert_eq!(p + s, p + ProjectivePoint::from(s));
+        assert_eq!(p - s, p - ProjectivePoint::from(s));
+        assert_eq!(q + s, q + JacobianPoint::from(s));
+        assert_eq!(q - s, q - JacobianPoint::from(s));
+    }
+
+    #[test]
+    fn test_cofactor_curve_affine() {
+        let mut rng = OsRng;
+        let p = JacobianPoint::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+
+        assert!(bool::from(CofactorCurveAffine::is_identity(
+            &<AffinePoint as CofactorCurveAffine>::identity()
+        )));
+        assert_eq!(
+            <AffinePoint as CofactorCurveAffine>::generator(),
+            AffinePoint::generator()
+        );
+        assert_eq!(CofactorCurveAffine::to_curve(&p.to_affine()), p);
+        assert_eq!(p.to_affine() * s, p * s);
+    }
+
+    #[test]
+    fn test_subgroup_encoding() {
+        let a = non_torsion_free_point();
+
+        // Both the checked and unchecked decodings reject
+        // points outside of the prime-order subgroup.
+        let bytes = a.to_compressed();
+        assert!(bool::from(SubgroupPoint::from_bytes(&bytes).is_none()));
+        assert!(bool::from(
+            SubgroupAffinePoint::from_bytes(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            SubgroupPoint::from_bytes_unchecked(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            SubgroupAffinePoint::from_bytes_unchecked(&bytes).is_none()
+        ));
+
+        let bytes = a.to_uncompressed();
+        assert!(bool::from(
+            SubgroupPoint::from_uncompressed(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            SubgroupAffinePoint::from_uncompressed(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            SubgroupPoint::from_uncompressed_unchecked(&bytes).is_none()
+        ));
+        assert!(bool::from(
+            SubgroupAffinePoint::from_uncompressed_unchecked(&bytes).is_none()
+        ));
+
+        let mut rng = OsRng;
+        let p = SubgroupPoint::random(&mut rng);
+        let p_affine = SubgroupAffinePoint::from(p);
+        assert_eq!(
+            SubgroupPoint::from_bytes_unchecked(&p.to_bytes()).unwrap(),
+            p
+        );
+        assert_eq!(
+            SubgroupAffinePoint::from_bytes_unchecked(&p_affine.to_bytes()).unwrap(),
+            p_affine
+        );
+        assert_eq!(
+            SubgroupPoint::from_uncompressed_unchecked(&p.to_uncompressed()).unwrap(),
+            p
+        );
+        assert_eq!(
+            SubgroupAffinePoint::from_uncompressed_unchecked(&p_affine.to_uncompressed()).unwrap(),
+            p_affine
+        );
+    }
+
+    #[test]
+    fn test_serde_subgroup() {
+        let mut rng = OsRng;
+        let p = SubgroupPoint::random(&mut rng);
+        let encoded = bincode::serialize(&p).unwrap();
+        assert_eq!(encoded.len(), 49);
+        assert_eq!(bincode::deserialize::<SubgroupPoint>(&encoded).unwrap(), p);
+
+        let p = SubgroupAffinePoint::from(p);
+        let encoded = bincode::serialize(&p).unwrap();
+        assert_eq!(
+            bincode::deserialize::<SubgroupAffinePoint>(&encoded).unwrap(),
+            p
+        );
+
+        let encoded = bincode::serialize(&non_torsion_free_point()).unwrap();
+        assert!(bincode::deserialize::<SubgroupPoint>(&encoded).is_err());
+        assert!(bincode::deserialize::<SubgroupAffinePoint>(&encoded).is_err());
+    }
+}
@@ -0,0 +1,618 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides tooling around the $h$-torsion of the cheetah curve.
+//!
+//! As the cofactor $h$ is squarefree and coprime to the subgroup order $q$,
+//! the group of points is cyclic of order $h.q$, and any point splits
+//! uniquely as the sum of a point of the prime-order subgroup and of a
+//! point of order dividing $h$.
+
+use crate::{AffinePoint, NafLookupTable, ProjectivePoint, Scalar, SubgroupPoint};
+use crate::{MINUS_SHIFT_POINT_ARRAY, SHIFT_POINT_MODIFIED_JACOBIAN};
+
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+use rand_core::RngCore;
+use subtle::Choice;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The curve cofactor h = 708537115134665106932687062569690615370,
+/// in little-endian byte representation.
+pub const COFACTOR: [u8; 32] = [
+    74, 78, 48, 84, 93, 63, 188, 73, 0, 97, 239, 113, 224, 72, 11, 21, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0,
+];
+
+/// The prime factors of the curve cofactor, in increasing order.
+/// The cofactor being squarefree, they all appear with multiplicity one.
+pub const COFACTOR_FACTORS: [u128; 6] = [2, 5, 29, 181, 155833, 86621679593707472449686472361];
+
+// The inverse of the cofactor modulo the subgroup order q
+const COFACTOR_INVERSE: Scalar =
+    scalar!("38397671999682150597468934737191386159377297233996982482964091252200431322916");
+
+/// The subgroup order q, in little-endian byte representation.
+pub(crate) const FQ_MODULUS_BYTES: [u8; 32] = [
+    207, 172, 212, 174, 62, 98, 67, 212, 34, 119, 21, 48, 35, 167, 122, 50, 181, 55, 10, 153, 15,
+    191, 63, 86, 208, 34, 63, 59, 155, 89, 242, 122,
+];
+
+// The width-5 NAF of the subgroup order q, given as an addition chain of
+// (doublings, digit) steps from most to least significant digit. As q is odd,
+// there are no trailing doublings, and the steps sum up to 256 doublings.
+//
+// This is the same recoding as the one `multiply_vartime` computes at runtime
+// when multiplying by q. The speedup of `is_torsion_free` over the generic
+// `is_torsion_free_vartime` (about 97 µs against 121 µs) comes from running
+// this chain in modified Jacobian coordinates, whose doublings are cheaper.
+const Q_WNAF_CHAIN: [(u32, i8); 44] = [
+    (1, 1),
+    (7, -5),
+    (7, -7),
+    (6, 11),
+    (6, 13),
+    (5, -5),
+    (5, 7),
+    (5, 13),
+    (8, -3),
+    (5, -15),
+    (5, 13),
+    (9, 11),
+    (5, -7),
+    (8, -1),
+    (10, -15),
+    (7, -3),
+    (5, -7),
+    (7, 5),
+    (6, 7),
+    (5, -1),
+    (5, -5),
+    (5, -13),
+    (5, 5),
+    (5, -1),
+    (5, -11),
+    (5, 7),
+    (6, 9),
+    (6, -13),
+    (7, 1),
+    (5, -11),
+    (5, 15),
+    (5, -3),
+    (5, -15),
+    (5, 13),
+    (5, 9),
+    (5, -15),
+    (5, -5),
+    (7, 9),
+    (9, -11),
+    (5, 15),
+    (6, -11),
+    (6, 11),
+    (6, -13),
+    (6, 15),
+];
+
+impl ProjectivePoint {
+    /// Returns a fixed generator of the $h$-torsion of the curve,
+    /// i.e. a point of order exactly $h$.
+    pub fn torsion_generator() -> ProjectivePoint {
+        ProjectivePoint::from(AffinePoint {
+            x: fp6!(
+                5686154863892338089,
+                13385809643687030840,
+                7451087493682255007,
+                6662260535106316960,
+                15347969329689725213,
+                596808008051912835,
+            ),
+            y: fp6!(
+                3565193697447654075,
+                12614815786281103394,
+                14374383512294550231,
+                1931966399855292462,
+                12086111439661567349,
+                176992429074565487,
+            ),
+            infinity: Choice::from(0u8),
+        })
+    }
+
+    /// Returns a point of order exactly `order`, or `None` if `order`
+    /// does not divide the curve cofactor.
+    ///
+    /// This is intended to craft adversarial points, that lie on the
+    /// curve but outside of the prime-order subgroup.
+    pub fn torsion_point(order: u128) -> Option<ProjectivePoint> {
+        let mut remaining = order;
+        let mut point = ProjectivePoint::torsion_generator();
+
+        // The h-torsion being cyclic, [h / order]T has order `order`.
+        for &factor in COFACTOR_FACTORS.iter() {
+            if remaining.is_multiple_of(factor) {
+                remaining /= factor;
+            } else {
+                let mut bytes = [0u8; 32];
+                bytes[0..16].copy_from_slice(&factor.to_le_bytes());
+                point = point.multiply_vartime(&bytes);
+            }
+        }
+
+        if remaining == 1 {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a uniformly random $h$-torsion point, as a multiple of the
+    /// torsion generator by a random 248-bit integer, whose distribution
+    /// modulo h is within statistical distance 2^-118 of the uniform one.
+    pub(crate) fn random_torsion_point(mut rng: impl RngCore) -> ProjectivePoint {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes[..31]);
+
+        ProjectivePoint::torsion_generator().multiply(&bytes)
+    }
+
+    /// Splits this point as P = P_q + P_h, where P_q lies in the prime-order
+    /// subgroup and P_h is an $h$-torsion point, and returns (P_q, P_h).
+    ///
+    /// **This operation is variable time with respect to the point.**
+    pub fn torsion_component(&self) -> (SubgroupPoint, ProjectivePoint) {
+        // P_q = [h.(h^-1 mod q)]P
+        let prime_order_part = self
+            .multiply_vartime(&COFACTOR_INVERSE.to_bytes())
+            .multiply_vartime(&COFACTOR);
+
+        (SubgroupPoint(prime_order_part), self - prime_order_part)
+    }
+}
+
+impl ProjectivePoint {
+    /// Returns true if all `points` are free of an $h$-torsion component,
+    /// by checking the sums of `security_bits` random subsets of them instead
+    /// of each point individually.
+    ///
+    /// A batch of torsion-free points is always accepted. If any point has a
+    /// non-trivial $h$-torsion component, each subset sum is torsion-free with
+    /// probability at most 1/2, so that such a batch is wrongly accepted with
+    /// probability at most 2^-`security_bits`.
+    ///
+    /// A single random linear combination cannot provide this guarantee: for
+    /// any prime factor l of the cofactor, a combination cancels out an l-torsion
+    /// component with probability 1/l, that is 1/2 for the factor 2. Random
+    /// coefficients larger than one bit would hence not lower the number of
+    /// combinations to check.
+    ///
+    /// The cost is thus O(`security_bits`) full torsion checks, namely
+    /// `security_bits` calls to `is_torsion_free` on top of about
+    /// `security_bits * points.len() / 2` point additions, instead of
+    /// `points.len()` calls to `is_torsion_free`. This is only faster than
+    /// individual checks when there are noticeably more points than
+    /// `security_bits`. A value of 128, matching the security level of the
+    /// curve, is recommended.
+    ///
+    /// Panics if `security_bits` is zero, as any batch would then be accepted.
+    ///
+    /// **This operation is variable time with respect to the points.**
+    pub fn batch_is_torsion_free_vartime(
+        points: &[ProjectivePoint],
+        security_bits: usize,
+        rng: impl RngCore,
+    ) -> bool {
+        batch_is_torsion_free_vartime(points, security_bits, rng)
+    }
+
+    /// Checks that all `points` are free of an $h$-torsion component, as done
+    /// by `batch_is_torsion_free_vartime`. If the batch check fails, every point
+    /// is checked individually and the result of `is_torsion_free` for each of
+    /// them is returned instead.
+    ///
+    /// Panics if `security_bits` is zero.
+    ///
+    /// **This operation is variable time with respect to the points.**
+    pub fn batch_check_torsion_free_vartime(
+        points: &[ProjectivePoint],
+        security_bits: usize,
+        rng: impl RngCore,
+    ) -> Result<(), Vec<Choice>> {
+        batch_check_torsion_free_vartime(points, security_bits, rng, Self::is_torsion_free)
+    }
+}
+
+impl AffinePoint {
+    /// Returns true if all `points` are free of an $h$-torsion component,
+    /// by checking the sums of `security_bits` random subsets of them, at
+    /// the cost of `security_bits` full torsion checks. See
+    /// `ProjectivePoint::batch_is_torsion_free_vartime` for the soundness
+    /// guarantees and the recommended value of `security_bits`.
+    ///
+    /// Panics if `security_bits` is zero.
+    ///
+    /// **This operation is variable time with respect to the points.**
+    pub fn batch_is_torsion_free_vartime(
+        points: &[AffinePoint],
+        security_bits: usize,
+        rng: impl RngCore,
+    ) -> bool {
+        batch_is_torsion_free_vartime(points, security_bits, rng)
+    }
+
+    /// Checks that all `points` are free of an $h$-torsion component, as done
+    /// by `batch_is_torsion_free_vartime`. If the batch check fails, every point
+    /// is checked individually and the result of `is_torsion_free` for each of
+    /// them is returned instead.
+    ///
+    /// Panics if `security_bits` is zero.
+    ///
+    /// **This operation is variable time with respect to the points.**
+    pub fn batch_check_torsion_free_vartime(
+        points: &[AffinePoint],
+        security_bits: usize,
+        rng: impl RngCore,
+    ) -> Result<(), Vec<Choice>> {
+        batch_check_torsion_free_vartime(points, security_bits, rng, Self::is_torsion_free)
+    }
+}
+
+/// Returns true if [q]P is the identity, where `table` holds the odd multiples
+/// of P, by following the fixed addition chain `Q_WNAF_CHAIN` with doublings
+/// in modified jacobian coordinates.
+///
+/// The chain being independent of P, this is constant time with respect to P.
+pub(crate) fn is_torsion_free_with_table(table: &NafLookupTable<8>) -> Choice {
+    let mut acc = *SHIFT_POINT_MODIFIED_JACOBIAN;
+
+    for &(doublings, digit) in Q_WNAF_CHAIN.iter() {
+        acc = acc.double_multi_unchecked(doublings);
+        acc = if digit > 0 {
+            acc.add_mixed_unchecked(&table.get_point(digit as usize))
+        } else {
+            acc.add_mixed_unchecked(&-table.get_point(-digit as usize))
+        };
+    }
+
+    acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[256])
+        .is_identity()
+}
+
+/// Checks that the sums of `security_bits` random subsets of `points` are
+/// torsion-free, stopping at the first failure. Each subset sum requires
+/// its own full torsion check.
+fn batch_is_torsion_free_vartime<P>(
+    points: &[P],
+    security_bits: usize,
+    mut rng: impl RngCore,
+) -> bool
+where
+    ProjectivePoint: for<'a> AddAssign<&'a P>,
+{
+    assert!(security_bits >= 1, "security_bits must be at least 1");
+
+    for _ in 0..security_bits {
+        let mut sum = ProjectivePoint::identity();
+        for chunk in points.chunks(64) {
+            let mut bits = rng.next_u64();
+            for point in chunk {
+                if bits & 1 == 1 {
+                    sum += point;
+                }
+                bits >>= 1;
+            }
+        }
+
+        if !bool::from(sum.is_torsion_free()) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Runs the batch check over `points`, falling back to `is_torsion_free`
+/// on each point if it fails.
+fn batch_check_torsion_free_vartime<P: Sync>(
+    points: &[P],
+    security_bits: usize,
+    rng: impl RngCore,
+    is_torsion_free: impl Fn(&P) -> Choice + Sync + Send,
+) -> Result<(), Vec<Choice>>
+where
+    ProjectivePoint: for<'a> AddAssign<&'a P>,
+{
+    if batch_is_torsion_free_vartime(points, security_bits, rng) {
+        return Ok(());
+    }
+
+    #[cfg(feature = "parallel")]
+    let results = points.par_iter().map(is_torsion_free).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let results = points.iter().map(is_torsion_free).collect();
+
+    Err(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recoding;
+    use crate::{CompressedPoint, JacobianPoint, SubgroupAffinePoint, UncompressedPoint};
+    use alloc::vec;
+    use rand_core::OsRng;
+
+    // Returns true if `point` has order exactly `order`
+    fn has_order(point: &ProjectivePoint, order: u128) -> bool {
+        let mut bytes = [0u8; 32];
+        bytes[0..16].copy_from_slice(&order.to_le_bytes());
+        if !bool::from(point.multiply_vartime(&bytes).is_identity()) {
+            return false;
+        }
+
+        COFACTOR_FACTORS
+            .iter()
+            .filter(|&&factor| order.is_multiple_of(factor))
+            .all(|&factor| {
+                bytes[0..16].copy_from_slice(&(order / factor).to_le_bytes());
+                !bool::from(point.multiply_vartime(&bytes).is_identity())
+            })
+    }
+
+    #[test]
+    fn test_cofactor_factors() {
+        // All factors but the last one fit together in 64 bits
+        let small: u128 = COFACTOR_FACTORS[..5].iter().product();
+        let large = COFACTOR_FACTORS[5];
+        let lo = small * (large as u64 as u128);
+        let hi = small * (large >> 64) + (lo >> 64);
+
+        let mut bytes = [0u8; 32];
+        bytes[0..8].copy_from_slice(&(lo as u64).to_le_bytes());
+        bytes[8..24].copy_from_slice(&hi.to_le_bytes());
+        assert_eq!(bytes, COFACTOR);
+
+        assert_eq!(
+            COFACTOR_INVERSE * Scalar::from_bytes_non_canonical(&COFACTOR),
+            Scalar::one()
+        );
+    }
+
+    #[test]
+    fn test_torsion_generator() {
+        let generator = ProjectivePoint::torsion_generator();
+        assert!(bool::from(generator.is_on_curve()));
+        assert!(bool::from(generator.clear_cofactor().is_identity()));
+        assert!(!bool::from(generator.is_torsion_free()));
+
+        // [h/l]T is never the identity
+        for &factor in COFACTOR_FACTORS.iter() {
+            let point = ProjectivePoint::torsion_point(factor).unwrap();
+            assert!(!bool::from(point.is_identity()));
+        }
+    }
+
+    #[test]
+    fn test_torsion_point() {
+        for order in [1, 2, 5, 10, 29, 181, 2 * 181, 155833, 5 * 29 * 155833] {
+            let point = ProjectivePoint::torsion_point(order).unwrap();
+            assert!(bool::from(point.is_on_curve()));
+            assert!(has_order(&point, order));
+        }
+
+        let order = 86621679593707472449686472361;
+        let point = ProjectivePoint::torsion_point(order).unwrap();
+        assert!(has_order(&point, order));
+
+        for order in [0, 3, 4, 25, 2 * 2 * 29] {
+            assert!(ProjectivePoint::torsion_point(order).is_none());
+        }
+    }
+
+    #[test]
+    fn test_torsion_component() {
+        let mut rng = OsRng;
+
+        for _ in 0..5 {
+            let subgroup_point = ProjectivePoint::from(SubgroupPoint::random(&mut rng));
+            let torsion_point = ProjectivePoint::torsion_point(2 * 29).unwrap();
+            let point = subgroup_point + torsion_point;
+
+            let (prime_order_part, torsion_part) = point.torsion_component();
+            assert_eq!(ProjectivePoint::from(prime_order_part), subgroup_point);
+            assert_eq!(torsion_part, torsion_point);
+            assert!(bool::from(prime_order_part.0.is_torsion_free()));
+        }
+
+        // Points of the subgroup have no torsion component
+        let point = ProjectivePoint::generator();
+        let (prime_order_part, torsion_part) = point.torsion_component();
+        assert_eq!(ProjectivePoint::from(prime_order_part), point);
+        assert!(bool::from(torsion_part.is_identity()));
+
+        // Points with a full h-torsion component decompose consistently
+        let point = ProjectivePoint::torsion_generator() + ProjectivePoint::generator();
+        let (prime_order_part, torsion_part) = point.torsion_component();
+        assert_eq!(
+            ProjectivePoint::from(prime_order_part) + torsion_part,
+            point
+        );
+        assert!(bool::from(torsion_part.clear_cofactor().is_identity()));
+    }
+
+    #[test]
+    fn test_random_torsion_point() {
+        let mut rng = OsRng;
+
+        let points: Vec<ProjectivePoint> = (0..10)
+            .map(|_| ProjectivePoint::random_torsion_point(&mut rng))
+            .collect();
+        for point in points.iter() {
+            assert!(bool::from(point.clear_cofactor().is_identity()));
+            assert!(!bool::from(point.is_torsion_free()));
+        }
+        assert!(points[1..].iter().any(|p| *p != points[0]));
+    }
+
+    #[test]
+    fn test_torsion_rejection() {
+        let mut rng = OsRng;
+
+        for &factor in COFACTOR_FACTORS.iter() {
+            let point = ProjectivePoint::from(SubgroupPoint::random(&mut rng))
+                + ProjectivePoint::torsion_point(factor).unwrap();
+            let affine = AffinePoint::from(point);
+
+            assert!(!bool::from(point.is_torsion_free()));
+            assert!(!bool::from(affine.is_torsion_free()));
+            assert!(bool::from(SubgroupPoint::from_point(&point).is_none()));
+            assert!(bool::from(
+                SubgroupAffinePoint::from_point(&affine).is_none()
+            ));
+
+            let compressed = CompressedPoint::from_affine(&affine);
+            assert!(bool::from(
+                AffinePoint::from_compressed(&compressed).is_none()
+            ));
+            assert_eq!(
+                AffinePoint::from_compressed_unchecked(&compressed).unwrap(),
+                affine
+            );
+
+            let uncompressed = UncompressedPoint::from_affine(&affine);
+            assert!(bool::from(
+                AffinePoint::from_uncompressed(&uncompressed).is_none()
+            ));
+            assert_eq!(
+                AffinePoint::from_uncompressed_unchecked(&uncompressed).unwrap(),
+                affine
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_is_torsion_free() {
+        let mut rng = OsRng;
+
+        let mut points: Vec<ProjectivePoint> = (0..100)
+            .map(|_| ProjectivePoint::from(SubgroupPoint::random(&mut rng)))
+            .collect();
+        let mut points_affine = vec![AffinePoint::identity(); points.len()];
+        ProjectivePoint::batch_normalize(&points, &mut points_affine);
+
+        assert!(ProjectivePoint::batch_is_torsion_free_vartime(
+            &points, 64, &mut rng
+        ));
+        assert!(AffinePoint::batch_is_torsion_free_vartime(
+            &points_affine,
+            64,
+            &mut rng
+        ));
+        assert!(ProjectivePoint::batch_check_torsion_free_vartime(&points, 64, &mut rng).is_ok());
+        assert!(ProjectivePoint::batch_is_torsion_free_vartime(
+            &[],
+            64,
+            &mut rng
+        ));
+
+        // A single point with a torsion component of any prime order is caught
+        for (i, &factor) in COFACTOR_FACTORS.iter().enumerate() {
+            let index = 17 * i;
+            let torsion_point = ProjectivePoint::torsion_point(factor).unwrap();
+            points[index] += torsion_point;
+            points_affine[index] = AffinePoint::from(points[index]);
+
+            assert!(!ProjectivePoint::batch_is_torsion_free_vartime(
+                &points, 64, &mut rng
+            ));
+            assert!(!AffinePoint::batch_is_torsion_free_vartime(
+                &points_affine,
+                64,
+                &mut rng
+            ));
+
+            let results =
+                AffinePoint::batch_check_torsion_free_vartime(&points_affine, 64, &mut rng)
+                    .unwrap_err();
+            assert_eq!(results.len(), points.len());
+            for (j, result) in results.iter().enumerate() {
+                assert_eq!(bool::from(*result), j != index);
+            }
+
+            points[index] -= torsion_point;
+            points_affine[index] = AffinePoint::from(points[index]);
+        }
+
+        // Torsion components cancelling out in some subsets are caught as well
+        let torsion_point = ProjectivePoint::torsion_point(2).unwrap();
+        points[3] += torsion_point;
+        points[4] += torsion_point;
+        let results =
+            ProjectivePoint::batch_check_torsion_free_vartime(&points, 64, &mut rng).unwrap_err();
+        assert_eq!(results.iter().filter(|r| !bool::from(**r)).count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_is_torsion_free_zero_security_bits() {
+        let points = [ProjectivePoint::torsion_generator()];
+        let _ = ProjectivePoint::batch_is_torsion_free_vartime(&points, 0, OsRng);
+    }
+
+    #[test]
+    fn test_q_wnaf_chain() {
+        let digits = recoding::to_wnaf_vartime(&FQ_MODULUS_BYTES, 5);
+
+        let mut chain = Vec::new();
+        let mut previous = 256;
+        for i in (0..256).rev() {
+            if digits[i] != 0 {
+                chain.push(((previous - i) as u32, digits[i]));
+                previous = i;
+            }
+        }
+
+        assert_eq!(previous, 0);
+        assert_eq!(chain, Q_WNAF_CHAIN.to_vec());
+    }
+
+    #[test]
+    fn test_is_torsion_free_against_reference() {
+        let mut rng = OsRng;
+
+        let mut points = vec![
+            ProjectivePoint::identity(),
+            ProjectivePoint::generator(),
+            ProjectivePoint::torsion_generator(),
+        ];
+        for &factor in COFACTOR_FACTORS.iter() {
+            let torsion_point = ProjectivePoint::torsion_point(factor).unwrap();
+            points.push(torsion_point);
+            points.push(ProjectivePoint::from(SubgroupPoint::random(&mut rng)) + torsion_point);
+        }
+        for _ in 0..10 {
+            points.push(ProjectivePoint::random(&mut rng));
+            points.push(ProjectivePoint::from(SubgroupPoint::random(&mut rng)));
+        }
+
+        for point in points.iter() {
+            let expected = bool::from(point.is_torsion_free_vartime());
+            assert_eq!(bool::from(point.is_torsion_free()), expected);
+
+            let affine = AffinePoint::from(point);
+            assert_eq!(bool::from(affine.is_torsion_free()), expected);
+            assert_eq!(bool::from(affine.is_torsion_free_vartime()), expected);
+
+            let jacobian = JacobianPoint::from(affine);
+            assert_eq!(bool::from(jacobian.is_torsion_free()), expected);
+            assert_eq!(bool::from(jacobian.is_torsion_free_vartime()), expected);
+        }
+    }
+}
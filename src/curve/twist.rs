@@ -0,0 +1,465 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides an implementation of the quadratic twist
+//! of the cheetah curve, in projective coordinates.
+//!
+//! The twist u.y^2 = x^3 + x + B shares its x-coordinates with the
+//! values for which x^3 + x + B is not a square in Fp6, i.e. with the
+//! invalid inputs of x-only protocols on cheetah. It is implemented
+//! through the isomorphic short Weierstrass model
+//! Y^2 = X^3 + u^2.X + u^3.B, with (X, Y) = (u.x, u^2.y).
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use super::B;
+
+use crate::Fp6;
+
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Order of the quadratic twist of the curve, as little-endian bytes.
+///
+/// twist_order = 2(p^6 + 1) - h.q
+///             = 39402006141350512473373051550956862238057064441100873236165904385045070597711503414021181831411683820762393765411374
+///             = 2 * 3 * 6567001023558418745562175258492810373009510740183478872694317397507511766285250569003530305235280636793732294235229
+///
+/// where h.q is the order of the curve, the last factor being a 382-bit composite integer.
+pub const TWIST_ORDER: [u8; 48] = [
+    46, 250, 14, 87, 201, 55, 170, 86, 75, 116, 10, 202, 20, 175, 132, 220, 25, 41, 167, 78, 60,
+    240, 52, 57, 140, 0, 0, 0, 130, 255, 255, 255, 89, 0, 0, 0, 206, 255, 255, 255, 20, 0, 0, 0,
+    250, 255, 255, 255,
+];
+
+// u, a quadratic non-residue of Fp6 (its norm being -7)
+const U: Fp6 = Fp6::new([0, 1, 0, 0, 0, 0]);
+
+// A' = u^2
+const TWIST_A: Fp6 = U.square();
+
+// B' = u^3.B
+const TWIST_B: Fp6 = U.mul(&TWIST_A).mul(&B);
+
+const TWIST_B3: Fp6 = TWIST_B.mul_by_u32(3);
+
+/// A point of the quadratic twist of the curve, in projective coordinates
+#[derive(Copy, Clone, Debug)]
+pub struct TwistPoint {
+    pub(crate) x: Fp6,
+    pub(crate) y: Fp6,
+    pub(crate) z: Fp6,
+}
+
+impl Default for TwistPoint {
+    fn default() -> TwistPoint {
+        TwistPoint::identity()
+    }
+}
+
+impl zeroize::DefaultIsZeroes for TwistPoint {}
+
+impl fmt::Display for TwistPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ConstantTimeEq for TwistPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Is (xz, yz, z) equal to (x'z', y'z', z') when converted to affine?
+
+        let x1 = self.x * other.z;
+        let x2 = other.x * self.z;
+
+        let y1 = self.y * other.z;
+        let y2 = other.y * self.z;
+
+        let self_is_zero = self.z.is_zero();
+        let other_is_zero = other.z.is_zero();
+
+        (self_is_zero & other_is_zero) // Both point at infinity
+            | ((!self_is_zero) & (!other_is_zero) & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+        // Neither point at infinity, coordinates are the same
+    }
+}
+
+impl ConditionallySelectable for TwistPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        TwistPoint {
+            x: Fp6::conditional_select(&a.x, &b.x, choice),
+            y: Fp6::conditional_select(&a.y, &b.y, choice),
+            z: Fp6::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl Eq for TwistPoint {}
+impl PartialEq for TwistPoint {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Neg for &TwistPoint {
+    type Output = TwistPoint;
+
+    #[inline]
+    fn neg(self) -> TwistPoint {
+        self.neg()
+    }
+}
+
+impl Neg for TwistPoint {
+    type Output = TwistPoint;
+
+    #[inline]
+    fn neg(self) -> TwistPoint {
+        -&self
+    }
+}
+
+impl Add<&TwistPoint> for &TwistPoint {
+    type Output = TwistPoint;
+
+    #[inline]
+    fn add(self, rhs: &TwistPoint) -> TwistPoint {
+        self.add(rhs)
+    }
+}
+
+impl Sub<&TwistPoint> for &TwistPoint {
+    type Output = TwistPoint;
+
+    #[inline]
+    fn sub(self, rhs: &TwistPoint) -> TwistPoint {
+        self + (-rhs)
+    }
+}
+
+impl_binops_additive!(TwistPoint, TwistPoint);
+
+impl<T> Sum<T> for TwistPoint
+where
+    T: Borrow<TwistPoint>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+    }
+}
+
+impl TwistPoint {
+    /// Returns the identity of the group: the point at infinity.
+    pub const fn identity() -> TwistPoint {
+        TwistPoint {
+            x: Fp6::zero(),
+            y: Fp6::one(),
+            z: Fp6::zero(),
+        }
+    }
+
+    /// Returns a point of the twist whose x-coordinate, in the cheetah
+    /// model, is `x`. Such a point exists if and only if x^3 + x + B
+    /// is not a square in Fp6, i.e. if `AffinePoint::from_x()` fails.
+    pub fn from_x(x: &Fp6) -> CtOption<TwistPoint> {
+        // Y^2 = X^3 + u^2.X + u^3.B = u^3.(x^3 + x + B)
+        let gx = (x.square() + Fp6::one()) * x + B;
+        let twist_x = U * x;
+
+        (U * TWIST_A * gx).sqrt().map(|y| TwistPoint {
+            x: twist_x,
+            y,
+            z: Fp6::one(),
+        })
+    }
+
+    /// Returns the x-coordinate of this point in the cheetah model,
+    /// or `None` if this point is the identity.
+    pub fn to_x(&self) -> CtOption<Fp6> {
+        let z_inv = (U * self.z).invert();
+
+        z_inv.map(|z_inv| self.x * z_inv)
+    }
+
+    /// Computes a random `TwistPoint` element
+    pub fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let x = Fp6::random(&mut rng);
+            let flip_sign = rng.next_u32() & 1 == 1;
+
+            let p = TwistPoint::from_x(&x);
+            if bool::from(p.is_some()) {
+                let p = p.unwrap();
+                return if flip_sign { -p } else { p };
+            }
+        }
+    }
+
+    /// Returns true if this element is the identity (the point at infinity).
+    #[inline]
+    pub fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+
+    /// Returns true if this point is on the twist. This should always return
+    /// true unless an "unchecked" API was used.
+    pub fn is_on_curve(&self) -> Choice {
+        // Y^2 Z = X^3 + A' X Z^2 + B' Z^3
+
+        let z2 = self.z.square();
+        (self.y.square() * self.z)
+            .ct_eq(&(self.x.square() * self.x + TWIST_A * self.x * z2 + TWIST_B * z2 * self.z))
+            | (self.z.is_zero())
+    }
+
+    /// Computes the negation of a point in projective coordinates
+    #[inline]
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+
+    /// Computes the doubling of this point.
+    pub fn double(&self) -> TwistPoint {
+        self.add(self)
+    }
+
+    /// Adds this point to another point.
+    pub fn add(&self, rhs: &TwistPoint) -> TwistPoint {
+        // Algorithm 1, https://eprint.iacr.org/2015/1060.pdf
+
+        let t0 = self.x * rhs.x;
+        let t1 = self.y * rhs.y;
+        let t2 = self.z * rhs.z;
+
+        let t3 = self.x + self.y;
+        let t4 = rhs.x + rhs.y;
+        let t3 = t3 * t4;
+
+        let t4 = t0 + t1;
+        let t3 = t3 - t4;
+        let t4 = self.x + self.z;
+
+        let t5 = rhs.x + rhs.z;
+        let t4 = t4 * t5;
+        let t5 = t0 + t2;
+
+        let t4 = t4 - t5;
+        let t5 = self.y + self.z;
+        let x3 = rhs.y + rhs.z;
+
+        let t5 = t5 * x3;
+        let x3 = t1 + t2;
+        let t5 = t5 - x3;
+
+        let z3 = TWIST_A * t4;
+        let x3 = TWIST_B3 * t2;
+        let z3 = x3 + z3;
+
+        let x3 = t1 - z3;
+        let z3 = t1 + z3;
+        let y3 = x3 * z3;
+
+        let t1 = t0.double();
+        let t1 = t1 + t0;
+        let t2 = TWIST_A * t2;
+
+        let t4 = TWIST_B3 * t4;
+        let t1 = t1 + t2;
+        let t2 = t0 - t2;
+
+        let t2 = TWIST_A * t2;
+        let t4 = t4 + t2;
+        let t0 = t1 * t4;
+
+        let y3 = y3 + t0;
+        let t0 = t5 * t4;
+        let x3 = t3 * x3;
+
+        let x3 = x3 - t0;
+        let t0 = t3 * t1;
+        let z3 = t5 * z3;
+
+        let z3 = z3 + t0;
+
+        TwistPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Performs a scalar multiplication of this point by the little-endian
+    /// integer given as bytes, which may be larger than a `Scalar` element,
+    /// with a double-and-add algorithm.
+    ///
+    /// This operation is constant time with respect to the value
+    /// of the integer, but not to its length in bytes.
+    pub fn multiply(&self, by: &[u8]) -> TwistPoint {
+        let mut acc = TwistPoint::identity();
+
+        for bit in by
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
+        {
+            acc = acc.double();
+            acc = TwistPoint::conditional_select(&acc, &(acc + self), bit);
+        }
+
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AffinePoint, Scalar};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_is_on_curve() {
+        assert!(bool::from(TwistPoint::identity().is_on_curve()));
+
+        let mut rng = OsRng;
+        for _ in 0..10 {
+            let point = TwistPoint::random(&mut rng);
+            assert!(bool::from(point.is_on_curve()));
+            assert!(!bool::from(point.is_identity()));
+        }
+
+        let mut point = TwistPoint::random(&mut rng);
+        point.y = point.y.double();
+        assert!(!bool::from(point.is_on_curve()));
+    }
+
+    #[test]
+    fn test_from_x() {
+        let mut rng = OsRng;
+
+        for _ in 0..20 {
+            let x = Fp6::random(&mut rng);
+            let twist_point = TwistPoint::from_x(&x);
+            let curve_point = AffinePoint::from_x(&x);
+
+            // Any x-coordinate lies either on the curve or on its twist
+            assert_ne!(
+                twist_point.is_some().unwrap_u8(),
+                curve_point.is_some().unwrap_u8()
+            );
+
+            if bool::from(twist_point.is_some()) {
+                let point = twist_point.unwrap();
+                assert!(bool::from(point.is_on_curve()));
+                assert_eq!(point.to_x().unwrap(), x);
+                assert_eq!((-point).to_x().unwrap(), x);
+            }
+        }
+
+        // x = 0 lies on the twist, as B is not a square
+        assert!(bool::from(TwistPoint::from_x(&Fp6::zero()).is_some()));
+        assert!(bool::from(TwistPoint::identity().to_x().is_none()));
+    }
+
+    #[test]
+    fn test_addition() {
+        let mut rng = OsRng;
+        let a = TwistPoint::random(&mut rng);
+        let b = TwistPoint::random(&mut rng);
+        let c = TwistPoint::random(&mut rng);
+        let identity = TwistPoint::identity();
+
+        assert_eq!(a + identity, a);
+        assert_eq!(identity + a, a);
+        assert!(bool::from((a - a).is_identity()));
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a.double(), a + a);
+        assert_eq!(a.double() - a, a);
+        assert!(bool::from((a + b).is_on_curve()));
+        assert!(bool::from(a.double().is_on_curve()));
+        assert_eq!([a, b, c].iter().sum::<TwistPoint>(), a + b + c);
+
+        // Projective coordinates are not unique
+        let scaled = TwistPoint {
+            x: a.x.double(),
+            y: a.y.double(),
+            z: a.z.double(),
+        };
+        assert_eq!(scaled, a);
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let mut rng = OsRng;
+        let point = TwistPoint::random(&mut rng);
+
+        let k = rng.next_u64() as u128;
+        let l = rng.next_u64() as u128;
+        assert_eq!(
+            point.multiply(&k.to_le_bytes()) + point.multiply(&l.to_le_bytes()),
+            point.multiply(&(k + l).to_le_bytes())
+        );
+        assert!(bool::from(point.multiply(&[0]).is_identity()));
+        assert_eq!(point.multiply(&[1]), point);
+        assert_eq!(point.multiply(&[3]), point.double() + point);
+        assert_eq!(point.multiply(&[]), TwistPoint::identity());
+
+        // The twist order annihilates every point
+        for _ in 0..5 {
+            let point = TwistPoint::random(&mut rng);
+            assert!(bool::from(point.multiply(&TWIST_ORDER).is_identity()));
+        }
+
+        // Regression vector for the double-and-add multiplication
+        let point = TwistPoint::from_x(&Fp6::zero()).unwrap();
+        let k = [
+            0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, 0xef, 0xcd, 0xab, 0x89, 0x67, 0x45,
+            0x23, 0x01, 0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, 0xef, 0xcd, 0xab, 0x89,
+            0x67, 0x45, 0x23, 0x01,
+        ];
+        assert_eq!(
+            point.multiply(&k).to_x().unwrap(),
+            Fp6::new([
+                0xf8b4368441207196,
+                0xc43e98e11d319dbc,
+                0xafebeb2bc514a6eb,
+                0xa88c08d90dcba3b1,
+                0x25fe2a31fff2eb04,
+                0x7ea18c6bef69f6d8,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_x_only_ladder_rejects_twist() {
+        let mut rng = OsRng;
+        let k = Scalar::random(&mut rng).to_bytes();
+
+        for _ in 0..5 {
+            let point = TwistPoint::random(&mut rng);
+            let x = point.to_x().unwrap();
+            assert!(bool::from(AffinePoint::multiply_x_only(&x, &k).is_none()));
+        }
+    }
+}
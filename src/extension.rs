@@ -0,0 +1,209 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides a generic trait for extensions of the
+//! prime field Fp, implemented by Fp itself (seen as the trivial
+//! extension), the cubic extension Fp3 and the sextic extension Fp6.
+
+use group::ff::{Field, PrimeField};
+
+use crate::fp::Fp;
+use crate::fp3::Fp3;
+use crate::fp6::Fp6;
+
+/// A finite field extension of a prime base field.
+///
+/// Elements are represented by their `DEGREE` coordinates over the base
+/// field, in increasing degree order.
+pub trait ExtensionField: Field + From<Self::BaseField> {
+    /// The prime field this field is an extension of
+    type BaseField: PrimeField;
+
+    /// The degree of the extension over the base field
+    const DEGREE: usize;
+
+    /// Embeds a base field element into this extension.
+    fn from_base(element: Self::BaseField) -> Self {
+        Self::from(element)
+    }
+
+    /// Constructs an element from its coordinates over the base field.
+    ///
+    /// Panics if `elements` does not contain exactly `DEGREE` elements.
+    fn from_base_slice(elements: &[Self::BaseField]) -> Self;
+
+    /// Writes the coordinates of this element over the base field into `output`.
+    ///
+    /// Panics if `output` does not contain exactly `DEGREE` elements.
+    fn to_base_slice(&self, output: &mut [Self::BaseField]);
+
+    /// Computes the Frobenius endomorphism
+    #[must_use]
+    fn frobenius(&self) -> Self;
+
+    /// Computes the multiplication of this element with a base field element
+    #[must_use]
+    fn mul_by_base(&self, rhs: &Self::BaseField) -> Self;
+}
+
+impl ExtensionField for Fp {
+    type BaseField = Fp;
+
+    const DEGREE: usize = 1;
+
+    fn from_base_slice(elements: &[Fp]) -> Self {
+        assert_eq!(elements.len(), Self::DEGREE, "invalid number of elements");
+
+        elements[0]
+    }
+
+    fn to_base_slice(&self, output: &mut [Fp]) {
+        assert_eq!(output.len(), Self::DEGREE, "invalid number of elements");
+
+        output[0] = *self;
+    }
+
+    fn frobenius(&self) -> Self {
+        *self
+    }
+
+    fn mul_by_base(&self, rhs: &Fp) -> Self {
+        self * rhs
+    }
+}
+
+impl ExtensionField for Fp3 {
+    type BaseField = Fp;
+
+    const DEGREE: usize = 3;
+
+    fn from_base_slice(elements: &[Fp]) -> Self {
+        assert_eq!(elements.len(), Self::DEGREE, "invalid number of elements");
+
+        Self::from([elements[0], elements[1], elements[2]])
+    }
+
+    fn to_base_slice(&self, output: &mut [Fp]) {
+        assert_eq!(output.len(), Self::DEGREE, "invalid number of elements");
+
+        output.copy_from_slice(&<[Fp; 3]>::from(self));
+    }
+
+    fn frobenius(&self) -> Self {
+        self.frobenius()
+    }
+
+    fn mul_by_base(&self, rhs: &Fp) -> Self {
+        self.mul_by_fp(rhs)
+    }
+}
+
+impl ExtensionField for Fp6 {
+    type BaseField = Fp;
+
+    const DEGREE: usize = 6;
+
+    fn from_base_slice(elements: &[Fp]) -> Self {
+        assert_eq!(elements.len(), Self::DEGREE, "invalid number of elements");
+
+        Self::from([
+            elements[0],
+            elements[1],
+            elements[2],
+            elements[3],
+            elements[4],
+            elements[5],
+        ])
+    }
+
+    fn to_base_slice(&self, output: &mut [Fp]) {
+        assert_eq!(output.len(), Self::DEGREE, "invalid number of elements");
+
+        output.copy_from_slice(&<[Fp; 6]>::from(self));
+    }
+
+    fn frobenius(&self) -> Self {
+        self.frobenius()
+    }
+
+    fn mul_by_base(&self, rhs: &Fp) -> Self {
+        self.mul_by_fp(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use rand_core::OsRng;
+
+    fn check_extension_field<F: ExtensionField<BaseField = Fp>>() {
+        let mut rng = OsRng;
+
+        for _ in 0..20 {
+            let a = F::random(&mut rng);
+            let b = F::random(&mut rng);
+            let s = Fp::random(&mut rng);
+
+            // Coordinates round-trip
+            let mut coordinates = vec![Fp::zero(); F::DEGREE];
+            a.to_base_slice(&mut coordinates);
+            assert_eq!(F::from_base_slice(&coordinates), a);
+
+            // Embedding is a ring morphism
+            let e = F::from_base(s);
+            assert_eq!(e, F::from(s));
+            assert_eq!(a.mul_by_base(&s), a * e);
+            assert_eq!(F::from_base(s * s), F::from_base(s).square());
+
+            let mut coordinates = vec![Fp::zero(); F::DEGREE];
+            e.to_base_slice(&mut coordinates);
+            assert_eq!(coordinates[0], s);
+            assert!(coordinates[1..].iter().all(|c| *c == Fp::zero()));
+
+            // Frobenius is the p-th power map, fixing the base field
+            assert_eq!(a.frobenius(), a.pow_vartime([0xffffffff00000001]));
+            assert_eq!(e.frobenius(), e);
+            assert_eq!((a * b).frobenius(), a.frobenius() * b.frobenius());
+
+            let mut c = a;
+            for _ in 0..F::DEGREE {
+                c = c.frobenius();
+            }
+            assert_eq!(c, a);
+        }
+    }
+
+    #[test]
+    fn test_extension_field_fp() {
+        check_extension_field::<Fp>();
+    }
+
+    #[test]
+    fn test_extension_field_fp3() {
+        check_extension_field::<Fp3>();
+    }
+
+    #[test]
+    fn test_extension_field_fp6() {
+        check_extension_field::<Fp6>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_base_slice_invalid_length() {
+        let _ = Fp6::from_base_slice(&[Fp::one(); 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_base_slice_invalid_length() {
+        let mut output = [Fp::zero(); 6];
+        Fp3::one().to_base_slice(&mut output);
+    }
+}
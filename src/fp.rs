@@ -17,7 +17,7 @@ use core::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::utils::{shl64_by_u32_with_carry, square_assign_multi, sub64_with_carry};
+use crate::utils::{parse_literal, shl64_by_u32_with_carry, square_assign_multi, sub64_with_carry};
 
 use group::ff::{Field, PrimeField};
 use rand_core::RngCore;
@@ -103,6 +103,19 @@ impl Fp {
         Self(value % M.0)
     }
 
+    /// Creates a new field element from a decimal or hexadecimal
+    /// (`0x`-prefixed) string literal, which may contain `_` separators.
+    ///
+    /// Panics if the literal is malformed or not lower than the modulus.
+    /// When evaluated in a `const` context, as done by the [`fp!`](crate::fp)
+    /// macro, this turns invalid literals into compilation errors.
+    pub const fn from_literal(literal: &str) -> Self {
+        let value = parse_literal::<1>(literal)[0];
+        assert!(value < M.0, "literal is not lower than the field modulus");
+
+        Self(value)
+    }
+
     /// Returns zero, the additive identity.
     #[inline]
     pub const fn zero() -> Self {
@@ -1029,6 +1042,26 @@ mod tests {
         assert_eq!(element.0, element_normalized.0);
     }
 
+    #[test]
+    fn test_from_literal() {
+        assert_eq!(Fp::from_literal("0"), Fp::zero());
+        assert_eq!(Fp::from_literal("42"), Fp::new(42));
+        assert_eq!(Fp::from_literal("0x2a"), Fp::new(42));
+        assert_eq!(Fp::from_literal("18446744069414584320"), -Fp::one());
+        assert_eq!(Fp::from_literal("0xffff_ffff_0000_0000"), -Fp::one());
+
+        const SEVEN: Fp = fp!(7);
+        assert_eq!(SEVEN, GENERATOR);
+        assert_eq!(fp!(0xffffffff00000000), -Fp::one());
+        assert_eq!(fp!("18_446_744_069_414_584_320"), -Fp::one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_literal_modulus() {
+        let _ = Fp::from_literal("18446744069414584321");
+    }
+
     // FIELD TRAIT
     // ================================================================================================
 
@@ -6,22 +6,44 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! This module implements arithmetic over the extension field Fp3.
-//! The implementation is minimal and not exposed through the public
-//! API. It is only for internal use in Fp6 arithmetic operations.
-
-use core::fmt::{self, Formatter};
+//! This module implements arithmetic over the extension field Fp3,
+//! defined with irreducible polynomial v^3 - 7.
+//!
+//! Fp3 is also the cubic subfield of Fp6, through the embedding v -> u^2.
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Formatter},
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
+use group::ff::Field;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+#[cfg(feature = "serialize")]
+use serde::de::Visitor;
+#[cfg(feature = "serialize")]
+use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::fp::reduce_u96;
 use crate::fp::Fp;
 use crate::fp6::Fp6;
 
 use crate::fp::TWO_ADICITY;
 
+// CONSTANTS
+// ================================================================================================
+
 const BETA: u128 = crate::fp::GENERATOR.0 as u128;
 
+// Frobenius coefficients: v^p = 18446744065119617025.v
+// and v^(2p) = 4294967295.v^2
+const FROBENIUS_COEFF_1: Fp = Fp(18446744065119617025);
+const FROBENIUS_COEFF_2: Fp = Fp(4294967295);
+
 // 2^32 root of unity = 2800184025912956819
 const TWO_ADIC_ROOT_OF_UNITY_P3: Fp3 = Fp3 {
     a0: Fp(2800184025912956819),
@@ -29,9 +51,17 @@ const TWO_ADIC_ROOT_OF_UNITY_P3: Fp3 = Fp3 {
     a2: Fp::zero(),
 };
 
+// FIELD ELEMENT
+// ================================================================================================
+
 #[derive(Copy, Clone)]
-/// An element of the extension GF(p^3)
-pub(crate) struct Fp3 {
+/// An element of the extension GF(p^3).
+///
+/// It represents the field extension element
+/// a2.v^2 + a1.v + a0
+/// where v is a root of the polynomial defining
+/// the cubic extension.
+pub struct Fp3 {
     /// First coefficient, lowest degree
     pub(crate) a0: Fp,
     /// Second coefficient
@@ -42,7 +72,7 @@ pub(crate) struct Fp3 {
 
 impl fmt::Debug for Fp3 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:?} + {:?}*u + {:?}*u^2", self.a0, self.a1, self.a2)
+        write!(f, "{:?} + {:?}*v + {:?}*v^2", self.a0, self.a1, self.a2)
     }
 }
 
@@ -52,6 +82,16 @@ impl Default for Fp3 {
     }
 }
 
+impl Hash for Fp3 {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.a0.hash(hasher);
+        self.a1.hash(hasher);
+        self.a2.hash(hasher);
+    }
+}
+
+impl zeroize::DefaultIsZeroes for Fp3 {}
+
 // When looking at the sextic extension as a towered one,
 // i.e. Fp6 = Fp3[Y]/(Y^2 − γ) with γ = δ = 7, we use the
 // lowest coefficient of the quadratic extension for
@@ -66,6 +106,67 @@ impl From<&Fp6> for Fp3 {
     }
 }
 
+impl From<Fp> for Fp3 {
+    fn from(f: Fp) -> Self {
+        Self {
+            a0: f,
+            a1: Fp::zero(),
+            a2: Fp::zero(),
+        }
+    }
+}
+
+impl From<[Fp; 3]> for Fp3 {
+    fn from(f: [Fp; 3]) -> Self {
+        Self {
+            a0: f[0],
+            a1: f[1],
+            a2: f[2],
+        }
+    }
+}
+
+impl From<&Fp3> for [Fp; 3] {
+    fn from(f: &Fp3) -> [Fp; 3] {
+        [f.a0, f.a1, f.a2]
+    }
+}
+
+impl From<Fp3> for [Fp; 3] {
+    fn from(f: Fp3) -> [Fp; 3] {
+        Self::from(&f)
+    }
+}
+
+impl From<u64> for Fp3 {
+    /// Converts a 64-bit value into a field element. If the value is greater than or equal to
+    /// the field modulus, modular reduction is silently performed.
+    fn from(value: u64) -> Self {
+        Self::from(Fp::new(value))
+    }
+}
+
+impl From<u32> for Fp3 {
+    /// Converts a 32-bit value into a field element.
+    fn from(value: u32) -> Self {
+        Self::from(Fp::new(value as u64))
+    }
+}
+
+impl From<u16> for Fp3 {
+    /// Converts a 16-bit value into a field element.
+    fn from(value: u16) -> Self {
+        Self::from(Fp::new(value as u64))
+    }
+}
+
+impl From<u8> for Fp3 {
+    /// Converts an 8-bit value into a field element.
+    fn from(value: u8) -> Self {
+        Self::from(Fp::new(value as u64))
+    }
+}
+
 impl ConstantTimeEq for Fp3 {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.a0.ct_eq(&other.a0) & self.a1.ct_eq(&other.a1) & self.a2.ct_eq(&other.a2)
@@ -90,10 +191,81 @@ impl ConditionallySelectable for Fp3 {
     }
 }
 
+impl Neg for &Fp3 {
+    type Output = Fp3;
+
+    #[inline]
+    fn neg(self) -> Fp3 {
+        self.neg()
+    }
+}
+
+impl Neg for Fp3 {
+    type Output = Fp3;
+
+    #[inline]
+    fn neg(self) -> Fp3 {
+        -&self
+    }
+}
+
+impl Sub<&Fp3> for &Fp3 {
+    type Output = Fp3;
+
+    #[inline]
+    fn sub(self, rhs: &Fp3) -> Fp3 {
+        self.sub(rhs)
+    }
+}
+
+impl Add<&Fp3> for &Fp3 {
+    type Output = Fp3;
+
+    #[inline]
+    fn add(self, rhs: &Fp3) -> Fp3 {
+        self.add(rhs)
+    }
+}
+
+impl<T> Sum<T> for Fp3
+where
+    T: Borrow<Fp3>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::zero(), |acc, item| acc + item.borrow())
+    }
+}
+
+impl Mul<&Fp3> for &Fp3 {
+    type Output = Fp3;
+
+    #[inline]
+    fn mul(self, rhs: &Fp3) -> Fp3 {
+        self.mul(rhs)
+    }
+}
+
+impl_binops_additive!(Fp3, Fp3);
+impl_binops_multiplicative!(Fp3, Fp3);
+
 impl Fp3 {
+    /// Creates a new field element from a [u64; 3] value.
+    /// The value is converted to canonical form by reducing
+    /// each coordinate if necessary.
+    pub const fn new(value: [u64; 3]) -> Self {
+        Self {
+            a0: Fp::new(value[0]),
+            a1: Fp::new(value[1]),
+            a2: Fp::new(value[2]),
+        }
+    }
+
     #[inline]
     /// The additive identity
-    pub(crate) const fn zero() -> Self {
+    pub const fn zero() -> Self {
         Self {
             a0: Fp::zero(),
             a1: Fp::zero(),
@@ -103,7 +275,7 @@ impl Fp3 {
 
     #[inline]
     /// The multiplicative identity
-    pub(crate) const fn one() -> Self {
+    pub const fn one() -> Self {
         Self {
             a0: Fp::one(),
             a1: Fp::zero(),
@@ -111,24 +283,97 @@ impl Fp3 {
         }
     }
 
+    /// Checks whether this element is zero or not
+    pub fn is_zero(&self) -> Choice {
+        self.a0.is_zero() & self.a1.is_zero() & self.a2.is_zero()
+    }
+
+    #[inline(always)]
+    /// Makes the element canonical by reducing each coordinate by the modulus if needed
+    pub const fn make_canonical(&self) -> Self {
+        Self {
+            a0: self.a0.make_canonical(),
+            a1: self.a1.make_canonical(),
+            a2: self.a2.make_canonical(),
+        }
+    }
+
+    /// Generates a random canonical element
+    pub fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = [0u8; 48];
+        rng.fill_bytes(&mut bytes);
+
+        Self::from_bytes_wide(&bytes)
+    }
+
+    /// Computes the double of a field element
+    #[inline]
+    pub const fn double(&self) -> Self {
+        Self {
+            a0: self.a0.double(),
+            a1: self.a1.double(),
+            a2: self.a2.double(),
+        }
+    }
+
+    /// Computes the summation of two field elements
+    #[inline]
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self {
+            a0: Fp::add(&self.a0, &rhs.a0),
+            a1: Fp::add(&self.a1, &rhs.a1),
+            a2: Fp::add(&self.a2, &rhs.a2),
+        }
+    }
+
+    /// Computes the difference of two field elements
+    #[inline]
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        Self {
+            a0: Fp::sub(&self.a0, &rhs.a0),
+            a1: Fp::sub(&self.a1, &rhs.a1),
+            a2: Fp::sub(&self.a2, &rhs.a2),
+        }
+    }
+
+    /// Computes the negation of a field element
+    #[inline]
+    pub const fn neg(&self) -> Self {
+        Self {
+            a0: Fp::neg(&self.a0),
+            a1: Fp::neg(&self.a1),
+            a2: Fp::neg(&self.a2),
+        }
+    }
+
+    #[inline]
+    /// Computes the multiplication of an Fp3 element with an Fp element
+    pub const fn mul_by_fp(&self, other: &Fp) -> Fp3 {
+        Self {
+            a0: Fp::mul(&self.a0, other),
+            a1: Fp::mul(&self.a1, other),
+            a2: Fp::mul(&self.a2, other),
+        }
+    }
+
     #[inline]
     /// Computes the multiplication of two Fp3 elements
-    pub(crate) const fn mul(&self, other: &Fp3) -> Fp3 {
-        let t00 = (&self.a0).mul(&other.a0).0 as u128;
-        let t01 = (&self.a1).mul(&other.a1).0 as u128;
-        let t02 = (&self.a2).mul(&other.a2).0 as u128;
+    pub const fn mul(&self, other: &Fp3) -> Fp3 {
+        let t00 = Fp::mul(&self.a0, &other.a0).0 as u128;
+        let t01 = Fp::mul(&self.a1, &other.a1).0 as u128;
+        let t02 = Fp::mul(&self.a2, &other.a2).0 as u128;
 
-        let s012 = (&self.a1).add(&self.a2);
-        let tmp = (&other.a1).add(&other.a2);
-        let s012 = (&s012).mul(&tmp).0 as u128;
+        let s012 = Fp::add(&self.a1, &self.a2);
+        let tmp = Fp::add(&other.a1, &other.a2);
+        let s012 = Fp::mul(&s012, &tmp).0 as u128;
 
-        let s001 = (&self.a0).add(&self.a1);
-        let tmp = (&other.a0).add(&other.a1);
-        let s001 = (&s001).mul(&tmp).0 as u128;
+        let s001 = Fp::add(&self.a0, &self.a1);
+        let tmp = Fp::add(&other.a0, &other.a1);
+        let s001 = Fp::mul(&s001, &tmp).0 as u128;
 
-        let s002 = (&self.a0).add(&self.a2);
-        let tmp = (&other.a0).add(&other.a2);
-        let s002 = (&s002).mul(&tmp).0 as u128;
+        let s002 = Fp::add(&self.a0, &self.a2);
+        let tmp = Fp::add(&other.a0, &other.a2);
+        let s002 = Fp::mul(&s002, &tmp).0 as u128;
 
         let d00 = t01 + t02;
         let d00 = s012 + 0x1fffffffe00000002 - d00;
@@ -154,19 +399,19 @@ impl Fp3 {
 
     /// Computes the square of a field element
     #[inline]
-    pub(crate) const fn square(&self) -> Self {
-        let t00 = (&self.a0).square().0 as u128;
-        let t01 = (&self.a1).square().0 as u128;
-        let t02 = (&self.a2).square().0 as u128;
+    pub const fn square(&self) -> Self {
+        let t00 = self.a0.square().0 as u128;
+        let t01 = self.a1.square().0 as u128;
+        let t02 = self.a2.square().0 as u128;
 
-        let s012 = (&self.a1).add(&self.a2);
-        let s012 = (&s012).square().0 as u128;
+        let s012 = Fp::add(&self.a1, &self.a2);
+        let s012 = s012.square().0 as u128;
 
-        let s001 = (&self.a0).add(&self.a1);
-        let s001 = (&s001).square().0 as u128;
+        let s001 = Fp::add(&self.a0, &self.a1);
+        let s001 = s001.square().0 as u128;
 
-        let s002 = (&self.a0).add(&self.a2);
-        let s002 = (&s002).square().0 as u128;
+        let s002 = Fp::add(&self.a0, &self.a2);
+        let s002 = s002.square().0 as u128;
 
         let d00 = t01 + t02;
         let d00 = s012 + 0x1fffffffe00000002 - d00;
@@ -191,7 +436,7 @@ impl Fp3 {
     }
 
     /// Computes the square root of this element, if it exists.
-    pub(crate) fn sqrt(&self) -> CtOption<Self> {
+    pub fn sqrt(&self) -> CtOption<Self> {
         // Tonelli-Shank's algorithm for q mod 16 = 1
         // See https://eprint.iacr.org/2020/1497.pdf, page 3 for a
         // constant time specification of the algorithm.
@@ -223,13 +468,43 @@ impl Fp3 {
         CtOption::new(s, (s.square()).ct_eq(self))
     }
 
+    /// Computes the multiplicative inverse of this field
+    /// element, returning None in the case that this element
+    /// is zero.
+    #[inline]
+    pub fn invert(&self) -> CtOption<Self> {
+        // The norm N(a) = a.a^p.a^(p^2) lies in Fp, hence
+        // a^-1 = a^p.a^(p^2) / N(a).
+
+        let t0 = self.frobenius();
+        let t1 = t0 * self.frobenius_double();
+        let norm = (t1 * self).a0;
+
+        norm.invert().map(|t| t1.mul_by_fp(&t))
+    }
+
+    /// Exponentiates `self` by `power`, where `power` is a
+    /// little-endian order integer exponent.
+    pub fn exp(&self, power: &[u64; 3]) -> Self {
+        let mut res = Self::one();
+        for e in power.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.square();
+                let mut tmp = res;
+                tmp *= self;
+                res.conditional_assign(&tmp, (((e >> i) & 1) as u8).into());
+            }
+        }
+        res
+    }
+
     /// Exponentiates `self` by `power`, where `power` is a
     /// little-endian order integer exponent.
     ///
     /// **This operation is variable time with respect
     /// to the exponent.** If the exponent is fixed,
     /// this operation is effectively constant time.
-    pub(crate) fn exp_vartime(&self, power: &[u64]) -> Self {
+    pub fn exp_vartime(&self, power: &[u64]) -> Self {
         let mut res = Self::one();
         for e in power.iter().rev() {
             for i in (0..64).rev() {
@@ -242,23 +517,203 @@ impl Fp3 {
         }
         res
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand_core::{OsRng, RngCore};
+    /// Computes the Frobenius endomorphism
+    #[inline]
+    pub const fn frobenius(&self) -> Self {
+        Self {
+            a0: self.a0,
+            a1: Fp::mul(&FROBENIUS_COEFF_1, &self.a1),
+            a2: Fp::mul(&FROBENIUS_COEFF_2, &self.a2),
+        }
+    }
+
+    /// Computes the Frobenius endomorphism twice
+    #[inline]
+    pub const fn frobenius_double(&self) -> Self {
+        Self {
+            a0: self.a0,
+            a1: Fp::mul(&FROBENIUS_COEFF_2, &self.a1),
+            a2: Fp::mul(&FROBENIUS_COEFF_1, &self.a2),
+        }
+    }
 
-    impl Fp3 {
-        /// Generates a random canonical element
-        fn random(mut rng: impl RngCore) -> Self {
+    /// Outputs the internal representation as 3 64-bit limbs after canonical reduction
+    pub const fn output_internal(&self) -> [u64; 3] {
+        [
+            self.a0.output_internal(),
+            self.a1.output_internal(),
+            self.a2.output_internal(),
+        ]
+    }
+
+    /// Converts an `Fp3` element into a byte representation in
+    /// little-endian byte order.
+    pub fn to_bytes(&self) -> [u8; 24] {
+        let mut bytes = [0u8; 24];
+
+        bytes[0..8].copy_from_slice(&self.a0.to_bytes());
+        bytes[8..16].copy_from_slice(&self.a1.to_bytes());
+        bytes[16..24].copy_from_slice(&self.a2.to_bytes());
+
+        bytes
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// a scalar into a `Fp3` element, failing if the input is not canonical.
+    pub fn from_bytes(bytes: &[u8; 24]) -> CtOption<Self> {
+        let mut array = [0u8; 8];
+
+        array.copy_from_slice(&bytes[0..8]);
+        let a0 = Fp::from_bytes(&array);
+
+        array.copy_from_slice(&bytes[8..16]);
+        let a1 = Fp::from_bytes(&array);
+
+        array.copy_from_slice(&bytes[16..24]);
+        let a2 = Fp::from_bytes(&array);
+
+        let is_some = a0.is_some() & a1.is_some() & a2.is_some();
+
+        CtOption::new(
             Self {
-                a0: Fp::random(&mut rng),
-                a1: Fp::random(&mut rng),
-                a2: Fp::random(&mut rng),
+                a0: a0.unwrap_or(Fp::zero()),
+                a1: a1.unwrap_or(Fp::zero()),
+                a2: a2.unwrap_or(Fp::zero()),
+            },
+            is_some,
+        )
+    }
+
+    /// Converts a 384-bit little endian integer into an `Fp3` element,
+    /// by reducing each 128-bit chunk by the modulus to obtain the
+    /// corresponding coefficient.
+    ///
+    /// The result is always returned in canonical form, and is
+    /// statistically close to uniform if the input is uniformly random.
+    pub fn from_bytes_wide(bytes: &[u8; 48]) -> Self {
+        let mut array = [0u8; 16];
+
+        array.copy_from_slice(&bytes[0..16]);
+        let a0 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[16..32]);
+        let a1 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[32..48]);
+        let a2 = Fp::from_bytes_wide(array);
+
+        Self { a0, a1, a2 }
+    }
+
+    /// Constructs an element of `Fp3` without checking that it is
+    /// canonical.
+    pub const fn from_raw_unchecked(value: [u64; 3]) -> Self {
+        Self {
+            a0: Fp::from_raw_unchecked(value[0]),
+            a1: Fp::from_raw_unchecked(value[1]),
+            a2: Fp::from_raw_unchecked(value[2]),
+        }
+    }
+}
+
+// FIELD TRAITS IMPLEMENTATION
+// ================================================================================================
+
+impl Field for Fp3 {
+    fn random(mut rng: impl RngCore) -> Self {
+        Self::random(&mut rng)
+    }
+
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn one() -> Self {
+        Self::one()
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::zero())
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        self.sqrt()
+    }
+}
+
+// SERDE SERIALIZATION
+// ================================================================================================
+
+#[cfg(feature = "serialize")]
+impl Serialize for Fp3 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(24)?;
+        for byte in self.to_bytes().iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for Fp3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Fp3Visitor;
+
+        impl<'de> Visitor<'de> for Fp3Visitor {
+            type Value = Fp3;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a valid field element")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Fp3, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; 24];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 24 bytes"))?;
+                }
+                let elem = Fp3::from_bytes(&bytes);
+                if bool::from(elem.is_none()) {
+                    Err(serde::de::Error::custom("decompression failed"))
+                } else {
+                    Ok(elem.unwrap())
+                }
             }
         }
+
+        deserializer.deserialize_tuple(24, Fp3Visitor)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
 
     // BASIC ALGEBRA
     // ================================================================================================
@@ -427,6 +882,111 @@ mod tests {
         assert_eq!(a.mul(&b), c);
     }
 
+    #[test]
+    fn test_addition_and_subtraction() {
+        let mut rng = OsRng;
+
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+            let b = Fp3::random(&mut rng);
+
+            assert_eq!(a + b - b, a);
+            assert_eq!(a + a, a.double());
+            assert_eq!(a + (-a), Fp3::zero());
+            assert_eq!(Fp6::from(a + b), Fp6::from(a) + Fp6::from(b));
+        }
+    }
+
+    #[test]
+    fn test_embedding_in_fp6() {
+        let mut rng = OsRng;
+
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+            let b = Fp3::random(&mut rng);
+
+            assert!(bool::from(Fp6::from(a).is_in_fp3()));
+            assert_eq!(Fp6::from(a * b), Fp6::from(a) * Fp6::from(b));
+            assert_eq!(Fp6::from(a.frobenius()), Fp6::from(a).frobenius());
+        }
+    }
+
+    #[test]
+    fn test_inversion() {
+        let mut rng = OsRng;
+
+        assert!(bool::from(Fp3::zero().invert().is_none()));
+        assert_eq!(Fp3::one().invert().unwrap(), Fp3::one());
+
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+            assert_eq!(a * a.invert().unwrap(), Fp3::one());
+            assert_eq!(
+                Fp6::from(a.invert().unwrap()),
+                Fp6::from(a).invert().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_invert_is_pow() {
+        let mut rng = OsRng;
+
+        let p3_minus_2 = [0xfffffffcffffffff, 0xfffffff900000005, 0xfffffffd00000005];
+
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+
+            assert_eq!(a.invert().unwrap(), a.exp(&p3_minus_2));
+            assert_eq!(a.invert().unwrap(), a.exp_vartime(&p3_minus_2));
+        }
+    }
+
+    #[test]
+    fn test_frobenius() {
+        let mut rng = OsRng;
+
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+
+            assert_eq!(a.frobenius(), a.exp(&[0xffffffff00000001, 0, 0]));
+            assert_eq!(a.frobenius_double(), a.frobenius().frobenius());
+            assert_eq!(a.frobenius_double().frobenius(), a);
+        }
+    }
+
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut a = Fp3::one();
+        a.zeroize();
+        assert!(bool::from(a.is_zero()));
+    }
+
+    // FIELD TRAIT
+    // ================================================================================================
+
+    #[test]
+    fn test_field_trait_methods() {
+        assert_eq!(<Fp3 as Field>::zero(), Fp3::new([0, 0, 0]));
+        assert_eq!(<Fp3 as Field>::one(), Fp3::new([1, 0, 0]));
+
+        let mut rng = OsRng;
+        let e = Fp3::random(&mut rng).square();
+
+        assert_eq!(<Fp3 as Field>::square(&e), e.square());
+        assert_eq!(<Fp3 as Field>::double(&e), e.double());
+
+        assert_eq!(<Fp3 as Field>::invert(&e).unwrap(), e.invert().unwrap());
+        assert!(bool::from(<Fp3 as Field>::invert(&Fp3::zero()).is_none()));
+
+        assert_eq!(<Fp3 as Field>::sqrt(&e).unwrap(), e.sqrt().unwrap());
+        assert!(bool::from(
+            <Fp3 as Field>::sqrt(&Fp3::new([5, 1, 0])).is_none()
+        ));
+    }
+
     // ROOTS OF UNITY
     // ================================================================================================
 
@@ -442,4 +1002,61 @@ mod tests {
             TWO_ADIC_ROOT_OF_UNITY_P3.exp_vartime(&[two_pow_32 - 1, 0, 0,])
         );
     }
+
+    // SERIALIZATION / DESERIALIZATION
+    // ================================================================================================
+
+    #[test]
+    fn test_from_bytes() {
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+            let bytes = a.to_bytes();
+            assert_eq!(a, Fp3::from_bytes(&bytes).unwrap());
+        }
+
+        let mut bytes = [0u8; 24];
+        bytes[8..16].copy_from_slice(&[1, 0, 0, 0, 255, 255, 255, 255]);
+        assert!(bool::from(Fp3::from_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_from_bytes_wide() {
+        assert_eq!(
+            Fp3::from_bytes_wide(&[0xff; 48]),
+            Fp3::new([0xfffffffe00000000; 3])
+        );
+
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let a = Fp3::random(&mut rng);
+            let mut bytes = [0u8; 48];
+            for (i, c) in a.output_internal().iter().enumerate() {
+                bytes[16 * i..16 * i + 8].copy_from_slice(&c.to_le_bytes());
+            }
+            assert_eq!(Fp3::from_bytes_wide(&bytes), a);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn test_serde() {
+        let mut rng = OsRng;
+        let element = Fp3::random(&mut rng);
+        let encoded = bincode::serialize(&element).unwrap();
+        let parsed: Fp3 = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(parsed, element);
+
+        // Check that the encoding is 24 bytes exactly
+        assert_eq!(encoded.len(), 24);
+
+        // Check that the encoding itself matches the usual one
+        assert_eq!(element, bincode::deserialize(&element.to_bytes()).unwrap());
+
+        // Check that invalid encodings fail
+        let wrong_encoding = [255; 24];
+        assert!(bincode::deserialize::<Fp3>(&wrong_encoding).is_err());
+
+        assert!(bincode::deserialize::<Fp3>(&encoded[0..23]).is_err());
+    }
 }
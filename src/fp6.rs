@@ -29,9 +29,63 @@ use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use crate::fp::reduce_u96;
 use crate::fp::Fp;
 use crate::fp3::Fp3;
+use crate::utils::mul64_with_carry;
+
+// CONSTANTS
+// ================================================================================================
 
 const BETA: u128 = crate::fp::GENERATOR.0 as u128;
 
+// Multiplicative generator g of order p^6 - 1
+// g = u + 4
+const GENERATOR: Fp6 = Fp6 {
+    c0: Fp(4),
+    c1: Fp(1),
+    c2: Fp(0),
+    c3: Fp(0),
+    c4: Fp(0),
+    c5: Fp(0),
+};
+
+// Two-adicity of the multiplicative group: (p^6 - 1) % 2^33 = 0
+pub(crate) const TWO_ADICITY: u32 = 33;
+
+// 2^33 root of unity = 135436726719221772.u^3
+const TWO_ADIC_ROOT_OF_UNITY: Fp6 = Fp6 {
+    c0: Fp(0),
+    c1: Fp(0),
+    c2: Fp(0),
+    c3: Fp(135436726719221772),
+    c4: Fp(0),
+    c5: Fp(0),
+};
+
+// Prime factorization of p^6 - 1, as (prime, multiplicity) pairs
+// with primes written in little-endian u64 limbs.
+// p^6 - 1 = 2^33 * 3^2 * 5 * 7 * 17 * 19 * 179 * 257 * 937 * 65537 * 724723
+//          * 60975709 * 7361031152998637 * 167034643597991036904547663171
+//          * 293716933257093487119720705751
+const MULTIPLICATIVE_GROUP_ORDER_FACTORS: [(&[u64], u32); 15] = [
+    (&[0x2], 33),
+    (&[0x3], 2),
+    (&[0x5], 1),
+    (&[0x7], 1),
+    (&[0x11], 1),
+    (&[0x13], 1),
+    (&[0xb3], 1),
+    (&[0x101], 1),
+    (&[0x3a9], 1),
+    (&[0x10001], 1),
+    (&[0xb0ef3], 1),
+    (&[0x3a26a5d], 1),
+    (&[0x1a26d19f0e18ed], 1),
+    (&[0x76d5470ea0f1bd43, 0x21bb7d19d], 1),
+    (&[0xa7f1ccdd7e8aeed7, 0x3b50cf3e8], 1),
+];
+
+// FIELD ELEMENT
+// ================================================================================================
+
 #[derive(Copy, Clone)]
 /// An element of the extension GF(p^6).
 ///
@@ -283,6 +337,24 @@ impl Fp6 {
         }
     }
 
+    /// Creates a new field element from the decimal or hexadecimal
+    /// (`0x`-prefixed) string literals of its six coordinates, in
+    /// increasing degree order.
+    ///
+    /// Panics if a literal is malformed or not lower than the modulus.
+    /// When evaluated in a `const` context, as done by the [`fp6!`](crate::fp6)
+    /// macro, this turns invalid literals into compilation errors.
+    pub const fn from_literals(literals: [&str; 6]) -> Self {
+        Self {
+            c0: Fp::from_literal(literals[0]),
+            c1: Fp::from_literal(literals[1]),
+            c2: Fp::from_literal(literals[2]),
+            c3: Fp::from_literal(literals[3]),
+            c4: Fp::from_literal(literals[4]),
+            c5: Fp::from_literal(literals[5]),
+        }
+    }
+
     #[inline]
     /// The additive identity
     pub const fn zero() -> Self {
@@ -334,14 +406,10 @@ impl Fp6 {
 
     /// Generates a random canonical element
     pub fn random(mut rng: impl RngCore) -> Self {
-        Self {
-            c0: Fp::random(&mut rng),
-            c1: Fp::random(&mut rng),
-            c2: Fp::random(&mut rng),
-            c3: Fp::random(&mut rng),
-            c4: Fp::random(&mut rng),
-            c5: Fp::random(&mut rng),
-        }
+        let mut bytes = [0u8; 96];
+        rng.fill_bytes(&mut bytes);
+
+        Self::from_bytes_wide(&bytes)
     }
 
     /// Returns whether or not this element is strictly lexicographically
@@ -909,6 +977,23 @@ impl Fp6 {
         CtOption::new(x, (x.square()).ct_eq(self))
     }
 
+    /// Returns whether this element is a square in Fp6, zero included.
+    ///
+    /// The quadratic character of an element of Fp6 is the one of its norm
+    /// over Fp, which is much cheaper to evaluate than a square root.
+    pub fn is_square(&self) -> Choice {
+        // norm = self^(1 + p + p^2 + p^3 + p^4 + p^5)
+        let a = self.mul(&self.frobenius());
+        let b = a.frobenius_double();
+        let norm = a.mul(&b).mul(&b.frobenius_double()).c0;
+
+        // Euler's criterion in Fp
+        // norm^((p - 1) // 2) = norm^0x7fffffff80000000
+        let legendre = norm.exp_vartime(0x7fffffff80000000);
+
+        legendre.ct_eq(&Fp::one()) | norm.is_zero()
+    }
+
     /// Computes the double of a field element
     #[inline]
     pub const fn double(&self) -> Self {
@@ -1068,6 +1153,143 @@ impl Fp6 {
         }
     }
 
+    /// Returns a fixed generator of the multiplicative group of Fp6,
+    /// of order p^6 - 1.
+    pub const fn multiplicative_generator() -> Self {
+        GENERATOR
+    }
+
+    /// Outputs a `Fp6` element of multiplicative order equals to 2^n
+    pub fn get_root_of_unity(n: u32) -> Self {
+        assert!(n != 0, "cannot get root of unity for n = 0");
+        assert!(n <= TWO_ADICITY, "order cannot exceed 2^{}", TWO_ADICITY);
+        let power = 1u64 << (TWO_ADICITY - n);
+
+        TWO_ADIC_ROOT_OF_UNITY.exp(&[power, 0, 0, 0, 0, 0])
+    }
+
+    /// Outputs a `Fp6` element of multiplicative order equals to 2^n
+    pub fn get_root_of_unity_vartime(n: u32) -> Self {
+        assert!(n != 0, "cannot get root of unity for n = 0");
+        assert!(n <= TWO_ADICITY, "order cannot exceed 2^{}", TWO_ADICITY);
+        let power = 1u64 << (TWO_ADICITY - n);
+
+        TWO_ADIC_ROOT_OF_UNITY.exp_vartime(&[power])
+    }
+
+    /// Returns the multiplicative order of this element as a
+    /// little-endian integer, failing if the element is zero.
+    ///
+    /// **This operation is variable time.**
+    pub fn multiplicative_order(&self) -> CtOption<[u64; 6]> {
+        let is_some = !self.is_zero();
+        if !bool::from(is_some) {
+            return CtOption::new([0u64; 6], is_some);
+        }
+
+        // For each prime factor q^e of p^6 - 1, the q-part of the order
+        // of self is the order of self^((p^6 - 1) / q^e).
+        let mut order = [1u64, 0, 0, 0, 0, 0];
+        for (i, (prime, multiplicity)) in MULTIPLICATIVE_GROUP_ORDER_FACTORS.iter().enumerate() {
+            let mut t = self.exp_by_cofactor_vartime(i, *multiplicity);
+            while t != Self::one() {
+                t = t.exp_vartime(prime);
+                order = mul_limbs(&order, prime);
+            }
+        }
+
+        CtOption::new(order, is_some)
+    }
+
+    /// Checks whether this element is a generator of the
+    /// multiplicative group of Fp6.
+    ///
+    /// **This operation is variable time.**
+    pub fn is_primitive(&self) -> bool {
+        if bool::from(self.is_zero()) {
+            return false;
+        }
+
+        (0..MULTIPLICATIVE_GROUP_ORDER_FACTORS.len())
+            .all(|i| self.exp_by_cofactor_vartime(i, 1) != Self::one())
+    }
+
+    /// Exponentiates `self` by (p^6 - 1) / q^k, where q is the
+    /// prime factor of index `index` in the factorization of p^6 - 1.
+    fn exp_by_cofactor_vartime(&self, index: usize, k: u32) -> Self {
+        let mut res = *self;
+        for (i, (prime, multiplicity)) in MULTIPLICATIVE_GROUP_ORDER_FACTORS.iter().enumerate() {
+            let multiplicity = if i == index {
+                multiplicity - k
+            } else {
+                *multiplicity
+            };
+            for _ in 0..multiplicity {
+                res = res.exp_vartime(prime);
+            }
+        }
+
+        res
+    }
+
+    /// Checks whether this element lies in the base field Fp,
+    /// i.e. whether it is fixed by the Frobenius endomorphism.
+    pub fn is_in_base_field(&self) -> Choice {
+        self.frobenius().ct_eq(self)
+    }
+
+    /// Checks whether this element lies in the quadratic subfield
+    /// Fp2 = Fp[u^3], i.e. whether it is fixed by the Frobenius
+    /// endomorphism applied twice.
+    pub fn is_in_fp2(&self) -> Choice {
+        self.frobenius_double().ct_eq(self)
+    }
+
+    /// Checks whether this element lies in the cubic subfield
+    /// Fp3 = Fp[u^2], i.e. whether it is fixed by the Frobenius
+    /// endomorphism applied thrice.
+    pub fn is_in_fp3(&self) -> Choice {
+        self.frobenius_triple().ct_eq(self)
+    }
+
+    /// Projects this element onto the base field Fp.
+    ///
+    /// This is the normalized trace (1/6).Tr_{Fp6/Fp}, which
+    /// amounts to keeping the lowest coefficient.
+    pub const fn project_to_base_field(&self) -> Fp {
+        self.c0
+    }
+
+    /// Projects this element onto the quadratic subfield Fp2 = Fp[u^3].
+    ///
+    /// This is the normalized relative trace (1/3).Tr_{Fp6/Fp2}, which
+    /// amounts to keeping the coefficients of 1 and u^3.
+    pub const fn project_to_fp2(&self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: Fp::zero(),
+            c2: Fp::zero(),
+            c3: self.c3,
+            c4: Fp::zero(),
+            c5: Fp::zero(),
+        }
+    }
+
+    /// Projects this element onto the cubic subfield Fp3 = Fp[u^2].
+    ///
+    /// This is the normalized relative trace (1/2).Tr_{Fp6/Fp3}, which
+    /// amounts to keeping the coefficients of 1, u^2 and u^4.
+    pub const fn project_to_fp3(&self) -> Self {
+        Self {
+            c0: self.c0,
+            c1: Fp::zero(),
+            c2: self.c2,
+            c3: Fp::zero(),
+            c4: self.c4,
+            c5: Fp::zero(),
+        }
+    }
+
     /// Outputs the internal representation as 6 64-bit limbs after canonical reduction
     pub const fn output_internal(&self) -> [u64; 6] {
         [
@@ -1134,6 +1356,43 @@ impl Fp6 {
         )
     }
 
+    /// Converts a 768-bit little endian integer into an `Fp6` element,
+    /// by reducing each 128-bit chunk by the modulus to obtain the
+    /// corresponding coefficient.
+    ///
+    /// The result is always returned in canonical form, and is
+    /// statistically close to uniform if the input is uniformly random.
+    pub fn from_bytes_wide(bytes: &[u8; 96]) -> Self {
+        let mut array = [0u8; 16];
+
+        array.copy_from_slice(&bytes[0..16]);
+        let c0 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[16..32]);
+        let c1 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[32..48]);
+        let c2 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[48..64]);
+        let c3 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[64..80]);
+        let c4 = Fp::from_bytes_wide(array);
+
+        array.copy_from_slice(&bytes[80..96]);
+        let c5 = Fp::from_bytes_wide(array);
+
+        Self {
+            c0,
+            c1,
+            c2,
+            c3,
+            c4,
+            c5,
+        }
+    }
+
     /// Constructs an element of `Fp6` without checking that it is
     /// canonical.
     pub const fn from_raw_unchecked(value: [u64; 6]) -> Self {
@@ -1243,6 +1502,22 @@ impl<'de> Deserialize<'de> for Fp6 {
     }
 }
 
+/// Computes a * b for little-endian integers a and b,
+/// truncating the result to 6 limbs.
+fn mul_limbs(a: &[u64; 6], b: &[u64]) -> [u64; 6] {
+    let mut res = [0u64; 6];
+    for (j, &bj) in b.iter().enumerate() {
+        let mut carry = 0;
+        for (i, &ai) in a.iter().enumerate().take(6 - j) {
+            let (r, c) = mul64_with_carry(res[i + j], ai, bj, carry);
+            res[i + j] = r;
+            carry = c;
+        }
+    }
+
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1495,6 +1770,22 @@ mod tests {
         assert_eq!(a.square(), b);
     }
 
+    #[test]
+    fn test_is_square() {
+        for _ in 0..100 {
+            let a = Fp6::random(&mut OsRng);
+            assert_eq!(a.is_square().unwrap_u8(), a.sqrt().is_some().unwrap_u8());
+            assert!(bool::from(a.square().is_square()));
+        }
+
+        assert!(bool::from(Fp6::zero().is_square()));
+        assert!(bool::from(Fp6::one().is_square()));
+
+        // u + 2
+        // is not a quadratic residue in Fp6
+        assert!(!bool::from(Fp6::new([2, 1, 0, 0, 0, 0]).is_square()));
+    }
+
     #[test]
     fn test_sqrt() {
         for _ in 0..100 {
@@ -1746,6 +2037,173 @@ mod tests {
         }
     }
 
+    // MULTIPLICATIVE GROUP
+    // ================================================================================================
+
+    const P6_MINUS_ONE: [u64; 6] = [
+        0xfffffffa00000000,
+        0xffffffce00000014,
+        0xffffff8200000059,
+        0xffffff820000008c,
+        0xffffffce00000059,
+        0xfffffffa00000014,
+    ];
+
+    #[test]
+    fn test_multiplicative_group_order_factors() {
+        let mut order = [1u64, 0, 0, 0, 0, 0];
+        for (prime, multiplicity) in MULTIPLICATIVE_GROUP_ORDER_FACTORS.iter() {
+            for _ in 0..*multiplicity {
+                order = mul_limbs(&order, prime);
+            }
+        }
+
+        assert_eq!(order, P6_MINUS_ONE);
+    }
+
+    #[test]
+    fn test_multiplicative_generator() {
+        let g = Fp6::multiplicative_generator();
+
+        assert!(g.is_primitive());
+        assert_eq!(g.multiplicative_order().unwrap(), P6_MINUS_ONE);
+        assert_eq!(g.exp(&P6_MINUS_ONE), Fp6::one());
+
+        assert!(!g.square().is_primitive());
+        assert!(!Fp6::one().is_primitive());
+        assert!(!Fp6::zero().is_primitive());
+        assert!(!Fp6::from(crate::fp::GENERATOR).is_primitive());
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let mut rng = OsRng;
+
+        assert!(bool::from(Fp6::zero().multiplicative_order().is_none()));
+        assert_eq!(
+            Fp6::one().multiplicative_order().unwrap(),
+            [1, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            (-Fp6::one()).multiplicative_order().unwrap(),
+            [2, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Fp6::from(crate::fp::GENERATOR)
+                .multiplicative_order()
+                .unwrap(),
+            [0xffffffff00000000, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            TWO_ADIC_ROOT_OF_UNITY.multiplicative_order().unwrap(),
+            [1 << 33, 0, 0, 0, 0, 0]
+        );
+
+        for _ in 0..10 {
+            let a = Fp6::random(&mut rng);
+            let order = a.multiplicative_order().unwrap();
+
+            assert_eq!(a.exp(&order), Fp6::one());
+            assert_eq!(order == P6_MINUS_ONE, a.is_primitive());
+        }
+    }
+
+    // ROOTS OF UNITY
+    // ================================================================================================
+
+    #[test]
+    fn test_get_root_of_unity() {
+        let two_pow_32 = 1u64 << 32;
+
+        let root_33 = Fp6::get_root_of_unity(33);
+        let root_33_vartime = Fp6::get_root_of_unity_vartime(33);
+        assert_eq!(TWO_ADIC_ROOT_OF_UNITY, root_33);
+        assert_eq!(TWO_ADIC_ROOT_OF_UNITY, root_33_vartime);
+        assert_eq!(
+            Fp6::multiplicative_generator().exp_vartime(&[
+                0xa7ffffffd,
+                0x2cffffffe7,
+                0x467fffffc1,
+                0x2cffffffc1,
+                0xa7fffffe7,
+                0x7ffffffd
+            ]),
+            root_33
+        );
+        assert_eq!(Fp6::one(), root_33.exp_vartime(&[two_pow_32]).square());
+        assert_ne!(Fp6::one(), root_33.exp_vartime(&[two_pow_32]));
+
+        let root_32 = Fp6::get_root_of_unity(32);
+        let root_32_vartime = Fp6::get_root_of_unity_vartime(32);
+        assert_eq!(root_33.square(), root_32);
+        assert_eq!(root_33.square(), root_32_vartime);
+        assert!(bool::from(root_32.is_in_base_field()));
+        assert_eq!(Fp6::one(), root_32.exp_vartime(&[two_pow_32]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_root_of_unity_zero() {
+        let _ = Fp6::get_root_of_unity(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_root_of_unity_too_large() {
+        let _ = Fp6::get_root_of_unity(TWO_ADICITY + 1);
+    }
+
+    #[test]
+    fn test_subfields() {
+        let mut rng = OsRng;
+
+        let base = Fp6::from(Fp::random(&mut rng));
+        assert!(bool::from(base.is_in_base_field()));
+        assert!(bool::from(base.is_in_fp2()));
+        assert!(bool::from(base.is_in_fp3()));
+
+        for _ in 0..100 {
+            let a = Fp6::random(&mut rng);
+
+            assert!(!bool::from(a.is_in_base_field()));
+            assert!(!bool::from(a.is_in_fp2()));
+            assert!(!bool::from(a.is_in_fp3()));
+
+            let a_fp = a.project_to_base_field();
+            let a_fp2 = a.project_to_fp2();
+            let a_fp3 = a.project_to_fp3();
+
+            assert!(bool::from(Fp6::from(a_fp).is_in_base_field()));
+            assert!(bool::from(a_fp2.is_in_fp2()));
+            assert!(bool::from(a_fp3.is_in_fp3()));
+
+            // Projections match the normalized relative traces
+            let trace_fp = a
+                + a.frobenius()
+                + a.frobenius_double()
+                + a.frobenius_triple()
+                + a.frobenius_double().frobenius_double()
+                + a.frobenius_double().frobenius_triple();
+            assert_eq!(Fp6::from(a_fp).mul_by_u32(6), trace_fp);
+
+            let trace_fp2 = a + a.frobenius_double() + a.frobenius_double().frobenius_double();
+            assert_eq!(a_fp2.triple(), trace_fp2);
+
+            let trace_fp3 = a + a.frobenius_triple();
+            assert_eq!(a_fp3.double(), trace_fp3);
+
+            // Projections are idempotent
+            assert_eq!(a_fp2.project_to_fp2(), a_fp2);
+            assert_eq!(a_fp3.project_to_fp3(), a_fp3);
+            assert_eq!(Fp6::from(a_fp), a_fp2.project_to_fp3());
+
+            // Subfields are closed under multiplication
+            let b = Fp6::random(&mut rng);
+            assert!(bool::from((a_fp2 * b.project_to_fp2()).is_in_fp2()));
+            assert!(bool::from((a_fp3 * b.project_to_fp3()).is_in_fp3()));
+        }
+    }
+
     #[test]
     fn test_lexicographic_largest() {
         assert!(!bool::from(Fp6::zero().lexicographically_largest()));
@@ -1832,6 +2290,31 @@ mod tests {
         assert_eq!(s.mul_by_high_fp3(&t), s * y);
     }
 
+    // INITIALIZATION
+    // ================================================================================================
+
+    #[test]
+    fn test_from_literals() {
+        assert_eq!(
+            Fp6::from_literals(["0", "0", "0", "0", "0", "0"]),
+            Fp6::zero()
+        );
+        assert_eq!(
+            Fp6::from_literals(["1", "2", "0x3", "4", "0x5", "18446744069414584320"]),
+            Fp6::new([1, 2, 3, 4, 5, 18446744069414584320])
+        );
+
+        const CURVE_B: Fp6 = fp6!(395, 1, 0, 0, 0, 0);
+        assert_eq!(CURVE_B, crate::B);
+        assert_eq!(fp6!(4, 1, "0", 0x0, 0, 0,), Fp6::multiplicative_generator());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_literals_modulus() {
+        let _ = Fp6::from_literals(["0", "0", "0", "0", "0", "18446744069414584321"]);
+    }
+
     // FIELD TRAIT
     // ================================================================================================
 
@@ -1997,6 +2480,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_from_bytes_wide() {
+        let mut bytes = [0u8; 96];
+        for i in 0..6 {
+            bytes[16 * i..16 * i + 8].copy_from_slice(&[0, 0, 0, 0, 255, 255, 255, 255]);
+        }
+        assert_eq!(Fp6::from_bytes_wide(&bytes), -Fp6::new([1, 1, 1, 1, 1, 1]));
+
+        assert_eq!(
+            Fp6::from_bytes_wide(&[0xff; 96]),
+            Fp6::new([0xfffffffe00000000; 6])
+        );
+
+        let mut rng = OsRng;
+        for _ in 0..100 {
+            let a = Fp6::random(&mut rng);
+            let mut bytes = [0u8; 96];
+            for (i, c) in a.output_internal().iter().enumerate() {
+                bytes[16 * i..16 * i + 8].copy_from_slice(&c.to_le_bytes());
+            }
+            assert_eq!(Fp6::from_bytes_wide(&bytes), a);
+        }
+    }
+
     #[test]
     #[cfg(feature = "serialize")]
     fn test_serde() {
@@ -0,0 +1,371 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module implements hashing of arbitrary byte strings to field
+//! elements, following the `hash_to_field` procedure of RFC 9380
+//! (https://www.rfc-editor.org/rfc/rfc9380.html#section-5) with the
+//! `expand_message_xmd` expander instantiated with SHA-256.
+
+use alloc::vec;
+use sha2::{Digest, Sha256};
+
+use crate::fp::{reduce_u128, Fp};
+use crate::fp3::Fp3;
+use crate::fp6::Fp6;
+
+// CONSTANTS
+// ================================================================================================
+
+// Output size of SHA-256, in bytes
+const B_IN_BYTES: usize = 32;
+
+// Input block size of SHA-256, in bytes
+const R_IN_BYTES: usize = 64;
+
+// Number of bytes sampled per base field coordinate, i.e.
+// L = ceil((ceil(log2(p)) + k) / 8) with k = 128 bits of security.
+const L: usize = 24;
+
+// Prefix used to shorten domain separation tags longer than 255 bytes
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+// EXPAND MESSAGE
+// ================================================================================================
+
+/// Expands a message `msg` with domain separation tag `dst` into
+/// `output.len()` uniformly random bytes, following `expand_message_xmd`
+/// from RFC 9380 with SHA-256.
+///
+/// Domain separation tags longer than 255 bytes are first hashed, as
+/// specified in section 5.3.3 of RFC 9380.
+///
+/// Panics if `output` is longer than 8160 bytes.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], output: &mut [u8]) {
+    let len_in_bytes = output.len();
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(
+        ell <= 255 && len_in_bytes <= 0xffff,
+        "requested output length is too large"
+    );
+
+    let hashed_dst;
+    let dst = if dst.len() > 255 {
+        hashed_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &hashed_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha256::new()
+        .chain_update([0u8; R_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    for (i, chunk) in output.chunks_mut(B_IN_BYTES).enumerate() {
+        if i > 0 {
+            // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+            let mut tmp = [0u8; B_IN_BYTES];
+            for (t, (a, b)) in tmp.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+                *t = a ^ b;
+            }
+            b_i = Sha256::new()
+                .chain_update(tmp)
+                .chain_update([(i + 1) as u8])
+                .chain_update(dst)
+                .chain_update(dst_len)
+                .finalize();
+        }
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+// HASH TO FIELD
+// ================================================================================================
+
+/// A field whose elements can be derived from uniformly random
+/// byte strings, following the `hash_to_field` procedure of RFC 9380.
+pub trait HashToField: Sized {
+    /// Number of uniform bytes consumed to produce a single element
+    const UNIFORM_BYTES_LENGTH: usize;
+
+    /// Converts `UNIFORM_BYTES_LENGTH` uniformly random bytes into a
+    /// field element, with statistical distance to uniform at most 2^-128.
+    ///
+    /// Each base field coordinate is obtained by interpreting 24 bytes as
+    /// a big-endian integer and reducing it modulo p.
+    ///
+    /// Panics if `bytes` does not contain exactly `UNIFORM_BYTES_LENGTH` bytes.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Hashes a message `msg` with domain separation tag `dst` into
+    /// `output.len()` field elements, which are independent and
+    /// indistinguishable from uniformly random ones.
+    fn hash_to_field(msg: &[u8], dst: &[u8], output: &mut [Self]) {
+        let mut uniform_bytes = vec![0u8; output.len() * Self::UNIFORM_BYTES_LENGTH];
+        expand_message_xmd(msg, dst, &mut uniform_bytes);
+
+        for (element, bytes) in output
+            .iter_mut()
+            .zip(uniform_bytes.chunks(Self::UNIFORM_BYTES_LENGTH))
+        {
+            *element = Self::from_uniform_bytes(bytes);
+        }
+    }
+}
+
+impl HashToField for Fp {
+    const UNIFORM_BYTES_LENGTH: usize = L;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), L, "invalid number of bytes");
+
+        // Write x = a.2^64 + b with a a 128-bit value and b a 64-bit one,
+        // and reduce x as (a mod p).2^64 + b.
+        let mut a = [0u8; 16];
+        a.copy_from_slice(&bytes[0..16]);
+        let mut b = [0u8; 8];
+        b.copy_from_slice(&bytes[16..24]);
+
+        let a = reduce_u128(u128::from_be_bytes(a)) as u128;
+        let x = (a << 64) | (u64::from_be_bytes(b) as u128);
+
+        Fp::from_raw_unchecked(reduce_u128(x)).make_canonical()
+    }
+}
+
+impl HashToField for Fp3 {
+    const UNIFORM_BYTES_LENGTH: usize = 3 * L;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 3 * L, "invalid number of bytes");
+
+        Fp3::from([
+            Fp::from_uniform_bytes(&bytes[0..L]),
+            Fp::from_uniform_bytes(&bytes[L..2 * L]),
+            Fp::from_uniform_bytes(&bytes[2 * L..3 * L]),
+        ])
+    }
+}
+
+impl HashToField for Fp6 {
+    const UNIFORM_BYTES_LENGTH: usize = 6 * L;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 6 * L, "invalid number of bytes");
+
+        Fp6::from([
+            Fp::from_uniform_bytes(&bytes[0..L]),
+            Fp::from_uniform_bytes(&bytes[L..2 * L]),
+            Fp::from_uniform_bytes(&bytes[2 * L..3 * L]),
+            Fp::from_uniform_bytes(&bytes[3 * L..4 * L]),
+            Fp::from_uniform_bytes(&bytes[4 * L..5 * L]),
+            Fp::from_uniform_bytes(&bytes[5 * L..6 * L]),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const DST: &[u8] = b"CHEETAH-V01-CS01-with-expander-SHA256-128";
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // EXPAND MESSAGE
+    // ================================================================================================
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // Test vectors from RFC 9380, appendix K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+        let vectors: [(&[u8], &str); 5] = [
+            (
+                b"",
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            (
+                b"abc",
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                 647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                 bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+        ];
+
+        for (msg, expected) in vectors.iter() {
+            let expected = from_hex(expected);
+            let mut output = vec![0u8; expected.len()];
+            expand_message_xmd(msg, dst, &mut output);
+
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_oversize_dst() {
+        let dst = [b'a'; 300];
+        let mut output = [0u8; 32];
+        expand_message_xmd(b"abc", &dst, &mut output);
+
+        assert_eq!(
+            output.to_vec(),
+            from_hex("70a19f343d2212a968303dfa919049b56982c2f8078234c7bff17150f4300811")
+        );
+
+        // An oversize DST is equivalent to its hashed version
+        let hashed_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        let mut expected = [0u8; 32];
+        expand_message_xmd(b"abc", &hashed_dst, &mut expected);
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expand_message_xmd_too_long() {
+        let mut output = vec![0u8; 255 * 32 + 1];
+        expand_message_xmd(b"abc", DST, &mut output);
+    }
+
+    // HASH TO FIELD
+    // ================================================================================================
+
+    #[test]
+    fn test_hash_to_fp() {
+        let mut output = [Fp::zero(); 2];
+
+        Fp::hash_to_field(b"", DST, &mut output);
+        assert_eq!(
+            output,
+            [Fp::new(12841975356113533284), Fp::new(14645858838182883394)]
+        );
+
+        Fp::hash_to_field(b"abc", DST, &mut output);
+        assert_eq!(
+            output,
+            [Fp::new(8155903860278203684), Fp::new(4030912442219688655)]
+        );
+    }
+
+    #[test]
+    fn test_hash_to_fp3() {
+        let mut output = [Fp3::zero(); 1];
+
+        Fp3::hash_to_field(b"abc", DST, &mut output);
+        assert_eq!(
+            output,
+            [Fp3::new([
+                2979423606115676624,
+                1089486553478638790,
+                15048315947555370061
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_hash_to_fp6() {
+        let mut output = [Fp6::zero(); 2];
+
+        Fp6::hash_to_field(b"", DST, &mut output);
+        assert_eq!(
+            output,
+            [
+                Fp6::new([
+                    6239524580365452533,
+                    17902988352727606210,
+                    16908412871125449183,
+                    1819925852600320665,
+                    455479356821691335,
+                    15277077974563004375
+                ]),
+                Fp6::new([
+                    4543803473934885563,
+                    14596265364063302177,
+                    14427510047876797205,
+                    16374425371319870515,
+                    919750527031473021,
+                    18066877567346936770
+                ])
+            ]
+        );
+
+        let mut output = [Fp6::zero(); 1];
+
+        Fp6::hash_to_field(b"abc", DST, &mut output);
+        assert_eq!(
+            output,
+            [Fp6::new([
+                801535576617028967,
+                15338392875610296463,
+                7845634930372584211,
+                18366090853275909545,
+                2170899687748088194,
+                8289017517088706020
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_from_uniform_bytes() {
+        // 2^192 - 1 = 0 mod p, as 2^96 = -1 mod p
+        assert_eq!(Fp::from_uniform_bytes(&[0xff; 24]), Fp::zero());
+
+        // p itself reduces to zero
+        let mut bytes = [0u8; 24];
+        bytes[16..24].copy_from_slice(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 1]);
+        assert_eq!(Fp::from_uniform_bytes(&bytes), Fp::zero());
+
+        // 2^64 = 2^32 - 1 mod p
+        let mut bytes = [0u8; 24];
+        bytes[15] = 1;
+        assert_eq!(Fp::from_uniform_bytes(&bytes), Fp::new(0xffffffff));
+    }
+}
@@ -30,6 +30,9 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
 #[macro_use]
 extern crate lazy_static;
 
@@ -39,35 +42,50 @@ pub use group;
 #[macro_use]
 mod utils;
 
+#[macro_use]
+mod macros;
+
 mod constants;
 mod lookup;
 mod naf_lookup;
 
 mod curve;
+mod extension;
 mod fp;
 mod fp3;
 mod fp6;
+mod hash_to_field;
+mod polynomial;
 mod scalar;
 
 pub use scalar::Scalar;
 
 pub use fp::Fp;
+pub use fp3::Fp3;
 pub use fp6::Fp6;
 
+pub use extension::ExtensionField;
+pub use hash_to_field::{expand_message_xmd, HashToField};
+pub use polynomial::Polynomial;
+
 pub(crate) use constants::SHIFT_POINT_MODIFIED_JACOBIAN;
 pub use constants::{
     BASEPOINT_LOOKUP, BASEPOINT_TABLE, MINUS_SHIFT_POINT_ARRAY, SHIFT_POINT_AFFINE,
     SHIFT_POINT_JACOBIAN, SHIFT_POINT_PROJECTIVE,
 };
 
-pub use lookup::{BasePointTable, LookupTable};
+pub use lookup::{BasePointTable, FixedBaseMsmTable, LookupTable};
 pub use naf_lookup::NafLookupTable;
 
 pub(crate) use curve::ModifiedJacobianPoint;
 pub use curve::{
-    AffinePoint, CompressedPoint, JacobianPoint, ProjectivePoint, UncompressedPoint, B,
+    derive_generators, AffinePoint, CompressedPoint, ElligatorSquaredPoint, JacobianPoint,
+    ProjectivePoint, SubgroupAffinePoint, SubgroupPoint, TwistPoint, UncompressedPoint, B,
+    COFACTOR, COFACTOR_FACTORS, ENCODE_TO_CURVE_SUITE_ID, HASH_TO_CURVE_SUITE_ID, TWIST_ORDER,
 };
 
+pub mod recoding;
+
 /// Helper methods for arithmetic reduction of integers mod p
 pub mod fp_arith_utils {
     pub use crate::fp::{reduce_u128, reduce_u96};
@@ -10,10 +10,20 @@
 //!
 //! Adapted from https://github.com/RustCrypto/elliptic-curves
 
-use crate::{AffinePoint, JacobianPoint, ProjectivePoint, Scalar};
+use crate::recoding;
+#[cfg(feature = "parallel")]
+use crate::utils::{parallel_chunk_size, MIN_PARALLEL_MSM_CHUNK_SIZE};
+use crate::{derive_generators, AffinePoint, JacobianPoint, ProjectivePoint, Scalar};
 use crate::{MINUS_SHIFT_POINT_ARRAY, SHIFT_POINT_PROJECTIVE};
 
+use alloc::vec::Vec;
+#[cfg(feature = "serialize")]
+use core::fmt;
 use core::ops::Mul;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{self, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
@@ -79,17 +89,16 @@ impl<const N: usize> LookupTable<N> {
     // To do so, we first compute |x|.P, and then conditionally
     // negate the result based on the sign of x.
     pub(crate) fn get_point(&self, x: i8) -> AffinePoint {
-        debug_assert!(x >= -(N as i8));
-        debug_assert!(x <= N as i8);
+        debug_assert!((x as i16).abs() <= N as i16);
 
-        // Compute xabs = |x|
-        let xmask = x >> 7;
-        let xabs = (x + xmask) ^ xmask;
+        // Compute xabs = |x|, widening x to handle -128
+        let xmask = (x >> 7) as i16;
+        let xabs = ((x as i16 + xmask) ^ xmask) as u16;
 
         // Get an array element in constant time
         let mut t = AffinePoint::identity();
         for j in 1..N + 1 {
-            let c = (xabs as u8).ct_eq(&(j as u8));
+            let c = xabs.ct_eq(&(j as u16));
             t.conditional_assign(&self.0[j - 1], c);
         }
         // Now t == |x| * p.
@@ -105,12 +114,11 @@ impl<const N: usize> LookupTable<N> {
     // To do so, we first compute |x|.P, and then conditionally
     // negate the result based on the sign of x.
     pub(crate) fn get_point_vartime(&self, x: i8) -> AffinePoint {
-        debug_assert!(x >= -(N as i8));
-        debug_assert!(x <= N as i8);
+        debug_assert!((x as i16).abs() <= N as i16);
 
-        // Compute xabs = |x|
-        let xmask = x >> 7;
-        let xabs = (x + xmask) ^ xmask;
+        // Compute xabs = |x|, widening x to handle -128
+        let xmask = (x >> 7) as i16;
+        let xabs = ((x as i16 + xmask) ^ xmask) as u16;
 
         // Get an array element
         let mut t = self.0[xabs as usize - 1];
@@ -130,10 +138,39 @@ impl<const N: usize> LookupTable<N> {
 /// efficient scalar multiplication with the Pippenger's algorith,
 /// https://cr.yp.to/papers/pippenger.pdfm.
 ///
+/// Scalars are recoded with signed digits of width `w = log2(N) + 1`, and
+/// each of the `T` lookup tables `[P_k, 2P_k, ..., N.P_k]` handles a range
+/// of consecutive digits, with `P_k = 2^(w.k.r).P` where `r = ceil(ceil(256 / w) / T)`
+/// is the number of passes over all tables, separated by `w` doublings.
+///
+/// Smaller tables, such as `BasePointTable<8, 16>`, trade memory
+/// for additional doublings, while wider windows such as
+/// `BasePointTable<128, 32>` need a single pass and fewer additions.
+/// `N` must be a power of two between 2 and 128, and each table must be
+/// used by at least one digit, otherwise compilation fails.
+///
+/// ```
+/// use cheetah::{BasePointTable, ProjectivePoint, Scalar};
+///
+/// let table = BasePointTable::<128, 32>::from_basepoint(&ProjectivePoint::generator());
+/// let s = Scalar::from(42u8);
+/// assert_eq!(&table * s, ProjectivePoint::generator() * s);
+/// ```
+///
+/// ```compile_fail
+/// use cheetah::{BasePointTable, ProjectivePoint};
+///
+/// // 6 is not a power of two
+/// let table = BasePointTable::<6, 32>::from_basepoint(&ProjectivePoint::generator());
+/// ```
+///
 /// Creating a base point table is costly, and hence should be
 /// done in the purpose of being used more than once.
+///
+/// The default parameters use 32 lookup tables of 8 elements,
+/// i.e. radix-16 digits processed in two passes.
 #[derive(Clone, Debug)]
-pub struct BasePointTable(pub [LookupTable<8>; 32]);
+pub struct BasePointTable<const N: usize = 8, const T: usize = 32>(pub [LookupTable<N>; T]);
 
 impl From<AffinePoint> for BasePointTable {
     fn from(p: AffinePoint) -> Self {
@@ -160,13 +197,73 @@ impl From<&ProjectivePoint> for BasePointTable {
 }
 
 impl BasePointTable {
-    /// Returns a precomputed table of multiples of a given point.
+    /// Returns a precomputed table of multiples of a given point,
+    /// with the default parameters.
+    ///
+    /// Other configurations are built with `from_basepoint`, as
+    /// default parameters are not used when inferring the type of
+    /// an expression.
     pub fn create(basepoint: &ProjectivePoint) -> Self {
-        let mut table = BasePointTable([LookupTable::from(basepoint); 32]);
-        let mut point = *basepoint;
-        for i in 1..32 {
-            point = point.double_multi(8);
-            table.0[i] = LookupTable::from(&point);
+        Self::from_basepoint(basepoint)
+    }
+}
+
+/// Upper bound on the number of signed digits of a recoded scalar, reached
+/// for the smallest window width of 2, used to recode scalars on the stack.
+const MAX_NUM_DIGITS: usize = 128;
+
+impl<const N: usize, const T: usize> BasePointTable<N, T> {
+    /// Width of the signed digits used to recode scalars.
+    const WINDOW_WIDTH: usize = {
+        assert!(
+            N.is_power_of_two() && N >= 2 && N <= 128,
+            "the table size must be a power of two between 2 and 128"
+        );
+        N.trailing_zeros() as usize + 1
+    };
+
+    /// Number of signed digits of a recoded scalar.
+    const NUM_DIGITS: usize = 256usize.div_ceil(Self::WINDOW_WIDTH);
+
+    /// Number of passes over all lookup tables, i.e. number
+    /// of consecutive digits handled by each lookup table.
+    const NUM_PASSES: usize = {
+        assert!(T >= 1, "there must be at least one lookup table");
+        let num_passes = Self::NUM_DIGITS.div_ceil(T);
+        assert!(
+            (T - 1) * num_passes < Self::NUM_DIGITS,
+            "all lookup tables must be used"
+        );
+        num_passes
+    };
+
+    /// Returns a precomputed table of multiples of a given point.
+    pub fn from_basepoint(basepoint: &ProjectivePoint) -> Self {
+        let mut table = BasePointTable([LookupTable::from(basepoint); T]);
+        let num_doublings = (Self::WINDOW_WIDTH * Self::NUM_PASSES) as u32;
+
+        #[cfg(feature = "parallel")]
+        {
+            // Only the doubling chain is sequential, the
+            // lookup tables are then built across threads.
+            let mut points = [*basepoint; T];
+            for i in 1..T {
+                points[i] = points[i - 1].double_multi(num_doublings);
+            }
+
+            table.0[1..]
+                .par_iter_mut()
+                .zip(points[1..].par_iter())
+                .for_each(|(lookup, point)| *lookup = LookupTable::from(point));
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            let mut point = *basepoint;
+            for i in 1..T {
+                point = point.double_multi(num_doublings);
+                table.0[i] = LookupTable::from(&point);
+            }
         }
 
         table
@@ -187,22 +284,28 @@ impl BasePointTable {
     /// using internally the Pippenger's algorithm.
     #[inline]
     pub fn multiply(&self, by: &[u8; 32]) -> ProjectivePoint {
-        let a = Scalar::bytes_to_radix_16(by);
+        let w = Self::WINDOW_WIDTH;
+        let r = Self::NUM_PASSES;
+        let mut digits = [0i8; MAX_NUM_DIGITS];
+        let a = &mut digits[..Self::NUM_DIGITS];
+        recoding::signed_radix_into(by, w, a);
 
         let tables = &self.0;
         let mut acc = *SHIFT_POINT_PROJECTIVE;
 
-        for i in (0..64).filter(|x| x % 2 == 1) {
-            acc = acc.add_mixed_unchecked(&tables[i / 2].get_point(a[i]));
-        }
+        // The digit of index i is handled by the table i / r, during the
+        // pass i % r, processing higher passes first.
+        for pass in (0..r).rev() {
+            for i in (pass..a.len()).step_by(r) {
+                acc = acc.add_mixed_unchecked(&tables[i / r].get_point(a[i]));
+            }
 
-        acc = acc.double_multi_unchecked(4);
-
-        for i in (0..64).filter(|x| x % 2 == 0) {
-            acc = acc.add_mixed_unchecked(&tables[i / 2].get_point(a[i]));
+            if pass > 0 {
+                acc = acc.double_multi_unchecked(w as u32);
+            }
         }
 
-        acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[4])
+        acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[w * (r - 1)])
     }
 
     /// Performs a mixed scalar multiplication from `by`
@@ -214,31 +317,432 @@ impl BasePointTable {
     /// this operation is effectively constant time.
     #[inline]
     pub fn multiply_vartime(&self, by: &[u8; 32]) -> ProjectivePoint {
-        let a = Scalar::bytes_to_radix_16(by);
+        let w = Self::WINDOW_WIDTH;
+        let r = Self::NUM_PASSES;
+        let mut digits = [0i8; MAX_NUM_DIGITS];
+        let len = recoding::signed_radix_into_vartime(by, w, &mut digits[..Self::NUM_DIGITS]);
+        let a = &digits[..len];
 
         let tables = &self.0;
         let mut acc = *SHIFT_POINT_PROJECTIVE;
 
-        for i in (0..64).filter(|x| x % 2 == 1) {
-            acc = acc.add_mixed_unchecked(&tables[i / 2].get_point(a[i]));
+        // We skip unset digits, and directly access table entries.
+        for pass in (0..r).rev() {
+            for i in (pass..a.len()).step_by(r).filter(|&i| a[i] != 0) {
+                acc = acc.add_mixed_unchecked(&tables[i / r].get_point_vartime(a[i]));
+            }
+
+            if pass > 0 {
+                acc = acc.double_multi_unchecked(w as u32);
+            }
+        }
+
+        acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[w * (r - 1)])
+    }
+}
+
+impl<const N: usize, const T: usize> Mul<&Scalar> for &BasePointTable<N, T> {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: &Scalar) -> Self::Output {
+        self.multiply(&scalar.to_bytes())
+    }
+}
+
+impl<const N: usize, const T: usize> Mul<&Scalar> for BasePointTable<N, T> {
+    type Output = ProjectivePoint;
+
+    #[inline]
+    fn mul(self, rhs: &Scalar) -> ProjectivePoint {
+        self.multiply(&rhs.to_bytes())
+    }
+}
+
+impl<const N: usize, const T: usize> Mul<Scalar> for &BasePointTable<N, T> {
+    type Output = ProjectivePoint;
+
+    #[inline]
+    fn mul(self, rhs: Scalar) -> ProjectivePoint {
+        self.multiply(&rhs.to_bytes())
+    }
+}
+
+impl<const N: usize, const T: usize> Mul<Scalar> for BasePointTable<N, T> {
+    type Output = ProjectivePoint;
+
+    #[inline]
+    fn mul(self, rhs: Scalar) -> ProjectivePoint {
+        self.multiply(&rhs.to_bytes())
+    }
+}
+
+/// A list of `BasePointTable` for a fixed sequence of points `[G_0, ..., G_n]`,
+/// to perform efficient multiscalar multiplications ∑ s[i].G[i] for arbitrary
+/// scalars s[i], with all tables sharing a single doubling chain.
+///
+/// Creating such table is costly, and hence should be done in the
+/// purpose of being used many times, for instance to compute Pedersen
+/// vector commitments with a fixed set of generators.
+///
+/// When serialized, only the points `G[i]` are encoded. The precomputed
+/// tables are rebuilt upon deserialization, so that untrusted encodings
+/// cannot provide inconsistent tables.
+#[derive(Clone, Debug)]
+pub struct FixedBaseMsmTable(pub(crate) Vec<BasePointTable>);
+
+impl From<&[AffinePoint]> for FixedBaseMsmTable {
+    fn from(points: &[AffinePoint]) -> Self {
+        Self::create(points)
+    }
+}
+
+impl FixedBaseMsmTable {
+    /// Returns precomputed tables of multiples of each of the given points.
+    pub fn create(points: &[AffinePoint]) -> Self {
+        #[cfg(feature = "parallel")]
+        let tables = points.par_iter().map(BasePointTable::from).collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let tables = points.iter().map(BasePointTable::from).collect();
+
+        Self(tables)
+    }
+
+    /// Returns precomputed tables of multiples of the `n` points deterministically
+    /// derived from the label `domain_tag`. See `derive_generators` for details.
+    pub fn from_domain_tag(domain_tag: &[u8], n: usize) -> Self {
+        let points: Vec<AffinePoint> = derive_generators(domain_tag, n)
+            .iter()
+            .map(AffinePoint::from)
+            .collect();
+
+        Self::create(&points)
+    }
+
+    /// Returns the number of points of this table.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether this table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the basepoints of this table.
+    pub fn get_basepoints(&self) -> Vec<AffinePoint> {
+        self.0
+            .iter()
+            .map(|table| table.get_basepoint_vartime())
+            .collect()
+    }
+
+    /// Performs the multiscalar multiplication ∑ s[i].G[i] with the s[i]
+    /// given as byte representations of `Scalar` elements, where the G[i]
+    /// are the basepoints of this table.
+    ///
+    /// # Panics
+    /// Panics if the number of scalars differs from the number of points.
+    pub fn multiply(&self, scalars: &[[u8; 32]]) -> ProjectivePoint {
+        assert_eq!(self.0.len(), scalars.len());
+
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = parallel_chunk_size(scalars.len(), MIN_PARALLEL_MSM_CHUNK_SIZE);
+            self.0
+                .par_chunks(chunk_size)
+                .zip(scalars.par_chunks(chunk_size))
+                .map(|(tables, scalars)| Self::multiply_serial(tables, scalars))
+                .reduce(ProjectivePoint::identity, |a, b| a + b)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        Self::multiply_serial(&self.0, scalars)
+    }
+
+    /// Sequential version of `multiply`, also used to process
+    /// each chunk of tables when the `parallel` feature is enabled.
+    fn multiply_serial(tables: &[BasePointTable], scalars: &[[u8; 32]]) -> ProjectivePoint {
+        let digits: Vec<[i8; 64]> = scalars.iter().map(recoding::to_signed_window_16).collect();
+
+        let mut acc = *SHIFT_POINT_PROJECTIVE;
+
+        for (table, a) in tables.iter().zip(&digits) {
+            for i in (0..64).filter(|x| x % 2 == 1) {
+                acc = acc.add_mixed_unchecked(&table.0[i / 2].get_point(a[i]));
+            }
         }
 
         acc = acc.double_multi_unchecked(4);
 
-        for i in (0..64).filter(|x| x % 2 == 0) {
-            acc = acc.add_mixed_unchecked(&tables[i / 2].get_point(a[i]));
+        for (table, a) in tables.iter().zip(&digits) {
+            for i in (0..64).filter(|x| x % 2 == 0) {
+                acc = acc.add_mixed_unchecked(&table.0[i / 2].get_point(a[i]));
+            }
         }
 
         acc.add_mixed_unchecked(&MINUS_SHIFT_POINT_ARRAY[4])
     }
+
+    /// Performs the multiscalar multiplication ∑ s[i].G[i] with the s[i]
+    /// given as byte representations of `Scalar` elements, where the G[i]
+    /// are the basepoints of this table.
+    ///
+    /// **This operation is variable time with respect
+    /// to the scalars.** If the scalars are fixed,
+    /// this operation is effectively constant time.
+    ///
+    /// # Panics
+    /// Panics if the number of scalars differs from the number of points.
+    pub fn multiply_vartime(&self, scalars: &[[u8; 32]]) -> ProjectivePoint {
+        assert_eq!(self.0.len(), scalars.len());
+
+        #[cfg(feature = "parallel")]
+        {
+            let chunk_size = parallel_chunk_size(scalars.len(), MIN_PARALLEL_MSM_CHUNK_SIZE);
+            self.0
+                .par_chunks(chunk_size)
+                .zip(scalars.par_chunks(chunk_size))
+                .map(|(tables, scalars)| Self::multiply_vartime_serial(tables, scalars))
+                .reduce(ProjectivePoint::identity, |a, b| a + b)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        Self::multiply_vartime_serial(&self.0, scalars)
+    }
+
+    /// Sequential version of `multiply_vartime`, also used to process
+    /// each chunk of tables when the `parallel` feature is enabled.
+    fn multiply_vartime_serial(tables: &[BasePointTable], scalars: &[[u8; 32]]) -> ProjectivePoint {
+        let digits: Vec<[i8; 64]> = scalars
+            .iter()
+            .map(recoding::to_signed_window_16_vartime)
+            .collect();
+
+        let mut acc = ProjectivePoint::identity();
+
+        for (table, a) in tables.iter().zip(&digits) {
+            for i in (0..64).filter(|x| x % 2 == 1 && a[*x] != 0) {
+                acc = acc.add_mixed(&table.0[i / 2].get_point_vartime(a[i]));
+            }
+        }
+
+        acc = acc.double_multi(4);
+
+        for (table, a) in tables.iter().zip(&digits) {
+            for i in (0..64).filter(|x| x % 2 == 0 && a[*x] != 0) {
+                acc = acc.add_mixed(&table.0[i / 2].get_point_vartime(a[i]));
+            }
+        }
+
+        acc
+    }
 }
 
-impl<'a, 'b> Mul<&'b Scalar> for &'a BasePointTable {
-    type Output = ProjectivePoint;
+// SERDE SERIALIZATION
+// ================================================================================================
+
+#[cfg(feature = "serialize")]
+impl Serialize for FixedBaseMsmTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for table in self.0.iter() {
+            seq.serialize_element(&table.get_basepoint_vartime())?;
+        }
+        seq.end()
+    }
+}
 
-    fn mul(self, scalar: &'b Scalar) -> Self::Output {
-        self.multiply(&scalar.to_bytes())
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for FixedBaseMsmTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FixedBaseMsmTableVisitor;
+
+        impl<'de> Visitor<'de> for FixedBaseMsmTableVisitor {
+            type Value = FixedBaseMsmTable;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of compressed points")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<FixedBaseMsmTable, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                // The length hint comes from the input, and is hence
+                // capped to avoid large allocations on malformed data.
+                let max_capacity = 4096 / core::mem::size_of::<AffinePoint>();
+                let capacity = seq.size_hint().unwrap_or(0).min(max_capacity);
+                let mut points = Vec::with_capacity(capacity);
+                while let Some(point) = seq.next_element::<AffinePoint>()? {
+                    points.push(point);
+                }
+
+                Ok(FixedBaseMsmTable::create(&points))
+            }
+        }
+
+        deserializer.deserialize_seq(FixedBaseMsmTableVisitor)
     }
 }
 
-impl_binops_multiplicative_mixed!(BasePointTable, Scalar, ProjectivePoint);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn naive_msm(points: &[AffinePoint], scalars: &[Scalar]) -> ProjectivePoint {
+        points
+            .iter()
+            .zip(scalars)
+            .fold(ProjectivePoint::identity(), |acc, (p, s)| {
+                acc + ProjectivePoint::from(p) * s
+            })
+    }
+
+    #[test]
+    fn test_lookup_table_get_point() {
+        let mut rng = OsRng;
+        let p = ProjectivePoint::random(&mut rng);
+        let table = LookupTable::<128>::from(&p);
+
+        for x in [-128i8, -127, -1, 1, 64, 127] {
+            let expected = if x < 0 {
+                -(p * Scalar::from((x as i16).unsigned_abs()))
+            } else {
+                p * Scalar::from(x as u16)
+            };
+            assert_eq!(ProjectivePoint::from(table.get_point(x)), expected);
+            assert_eq!(ProjectivePoint::from(table.get_point_vartime(x)), expected);
+        }
+        assert!(bool::from(table.get_point(0).is_identity()));
+    }
+
+    fn check_basepoint_table<const N: usize, const T: usize>() {
+        let mut rng = OsRng;
+        let p = ProjectivePoint::random(&mut rng);
+        let table = BasePointTable::<N, T>::from_basepoint(&p);
+        assert_eq!(table.get_basepoint(), AffinePoint::from(p));
+
+        let mut scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+        scalars.push(Scalar::zero());
+        scalars.push(Scalar::one());
+        scalars.push(-Scalar::one());
+
+        for s in scalars {
+            assert_eq!(p * s, &table * s);
+            assert_eq!(p * s, table.multiply(&s.to_bytes()));
+            assert_eq!(p * s, table.multiply_vartime(&s.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_basepoint_table_configurations() {
+        check_basepoint_table::<8, 32>();
+        check_basepoint_table::<8, 16>();
+        check_basepoint_table::<2, 8>();
+        check_basepoint_table::<16, 13>();
+        check_basepoint_table::<64, 10>();
+        check_basepoint_table::<128, 32>();
+    }
+
+    #[test]
+    fn test_basepoint_table_from_lookup_tables() {
+        let mut rng = OsRng;
+        let p = ProjectivePoint::random(&mut rng);
+        let table = BasePointTable::create(&p);
+        let rebuilt = BasePointTable(table.0);
+
+        let s = Scalar::random(&mut rng);
+        assert_eq!(&rebuilt * s, p * s);
+    }
+
+    #[test]
+    fn test_fixed_base_msm() {
+        let mut rng = OsRng;
+        let mut points: Vec<AffinePoint> = (0..20).map(|_| AffinePoint::random(&mut rng)).collect();
+        points[3] = AffinePoint::identity();
+        let table = FixedBaseMsmTable::create(&points);
+        assert_eq!(table.len(), 20);
+        assert_eq!(table.get_basepoints(), points);
+
+        for _ in 0..5 {
+            let mut scalars: Vec<Scalar> = (0..20).map(|_| Scalar::random(&mut rng)).collect();
+            scalars[0] = Scalar::zero();
+            scalars[1] = -Scalar::one();
+            scalars[2] = Scalar::one();
+
+            let expected = naive_msm(&points, &scalars);
+            let scalars: Vec<[u8; 32]> = scalars.iter().map(|s| s.to_bytes()).collect();
+
+            assert_eq!(table.multiply(&scalars), expected);
+            assert_eq!(table.multiply_vartime(&scalars), expected);
+        }
+
+        // Zero scalars
+        let scalars = vec![[0u8; 32]; 20];
+        assert!(bool::from(table.multiply(&scalars).is_identity()));
+        assert!(bool::from(table.multiply_vartime(&scalars).is_identity()));
+
+        // Empty table
+        let table = FixedBaseMsmTable::create(&[]);
+        assert!(table.is_empty());
+        assert!(bool::from(table.multiply(&[]).is_identity()));
+        assert!(bool::from(table.multiply_vartime(&[]).is_identity()));
+    }
+
+    #[test]
+    fn test_fixed_base_msm_from_domain_tag() {
+        let mut rng = OsRng;
+        let generators: Vec<AffinePoint> = derive_generators(b"cheetah-test", 4)
+            .iter()
+            .map(AffinePoint::from)
+            .collect();
+        let table = FixedBaseMsmTable::from_domain_tag(b"cheetah-test", 4);
+        assert_eq!(table.get_basepoints(), generators);
+
+        let scalars: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut rng)).collect();
+        let expected = naive_msm(&generators, &scalars);
+        let scalars: Vec<[u8; 32]> = scalars.iter().map(|s| s.to_bytes()).collect();
+        assert_eq!(table.multiply(&scalars), expected);
+        assert_eq!(table.multiply_vartime(&scalars), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fixed_base_msm_invalid_length() {
+        let table = FixedBaseMsmTable::create(&[AffinePoint::generator(); 3]);
+        let _ = table.multiply(&[[0u8; 32]; 2]);
+    }
+
+    #[test]
+    fn test_serde_fixed_base_msm() {
+        let mut rng = OsRng;
+        let points: Vec<AffinePoint> = (0..5).map(|_| AffinePoint::random(&mut rng)).collect();
+        let table = FixedBaseMsmTable::create(&points);
+
+        let encoded = bincode::serialize(&table).unwrap();
+        let parsed: FixedBaseMsmTable = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(parsed.get_basepoints(), points);
+
+        let scalars: Vec<[u8; 32]> = (0..5)
+            .map(|_| Scalar::random(&mut rng).to_bytes())
+            .collect();
+        assert_eq!(parsed.multiply(&scalars), table.multiply(&scalars));
+
+        // Check that invalid encodings fail
+        assert!(bincode::deserialize::<FixedBaseMsmTable>(&encoded[0..encoded.len() - 1]).is_err());
+
+        // Check that a bogus length does not trigger a large allocation
+        let mut encoded = encoded;
+        encoded[0..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(bincode::deserialize::<FixedBaseMsmTable>(&encoded).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides macros to define field elements from decimal
+//! or hexadecimal literals, parsed at compile time.
+
+/// Creates an `Fp` element from a decimal or hexadecimal integer literal,
+/// or from a string literal holding one.
+///
+/// The literal is parsed at compile time, and literals that are malformed
+/// or not lower than the field modulus are rejected with a compilation error.
+///
+/// ```
+/// use cheetah::{fp, Fp};
+///
+/// const SEVEN: Fp = fp!(7);
+/// assert_eq!(SEVEN, Fp::new(7));
+/// assert_eq!(fp!(0xffffffff00000000), -Fp::one());
+/// assert_eq!(fp!("18446744069414584320"), -Fp::one());
+/// ```
+///
+/// ```compile_fail
+/// // p = 18446744069414584321 is not a canonical element
+/// let _ = cheetah::fp!(18446744069414584321);
+/// ```
+#[macro_export]
+macro_rules! fp {
+    ($value:literal) => {{
+        const VALUE: $crate::Fp = $crate::Fp::from_literal(stringify!($value));
+        VALUE
+    }};
+}
+
+/// Creates an `Fp6` element from the decimal or hexadecimal integer literals,
+/// or string literals holding them, of its six coordinates in increasing
+/// degree order.
+///
+/// The literals are parsed at compile time, and literals that are malformed
+/// or not lower than the field modulus are rejected with a compilation error.
+///
+/// ```
+/// use cheetah::{fp6, Fp6, B};
+///
+/// const CURVE_B: Fp6 = fp6!(395, 1, 0, 0, 0, 0);
+/// assert_eq!(CURVE_B, B);
+/// ```
+///
+/// ```compile_fail
+/// // p = 18446744069414584321 is not a canonical element
+/// let _ = cheetah::fp6!(0, 0, 0, 0, 0, 18446744069414584321);
+/// ```
+#[macro_export]
+macro_rules! fp6 {
+    ($c0:literal, $c1:literal, $c2:literal, $c3:literal, $c4:literal, $c5:literal $(,)?) => {{
+        const VALUE: $crate::Fp6 = $crate::Fp6::from_literals([
+            stringify!($c0),
+            stringify!($c1),
+            stringify!($c2),
+            stringify!($c3),
+            stringify!($c4),
+            stringify!($c5),
+        ]);
+        VALUE
+    }};
+}
+
+/// Creates a `Scalar` element from a decimal or hexadecimal integer literal,
+/// or from a string literal holding one.
+///
+/// The literal is parsed at compile time, and literals that are malformed
+/// or not lower than the scalar field modulus are rejected with a compilation
+/// error.
+///
+/// ```
+/// use cheetah::{scalar, Scalar};
+///
+/// const MINUS_ONE: Scalar = scalar!(
+///     55610362957290864006699123731285679659474893560816383126640993521607086746830
+/// );
+/// assert_eq!(MINUS_ONE, -Scalar::one());
+/// assert_eq!(scalar!(0x2a), Scalar::from(42u8));
+/// ```
+///
+/// ```compile_fail
+/// // The scalar field modulus is not a canonical element
+/// let _ = cheetah::scalar!(
+///     55610362957290864006699123731285679659474893560816383126640993521607086746831
+/// );
+/// ```
+#[macro_export]
+macro_rules! scalar {
+    ($value:literal) => {{
+        const VALUE: $crate::Scalar = $crate::Scalar::from_literal(stringify!($value));
+        VALUE
+    }};
+}
@@ -10,6 +10,7 @@
 
 use crate::{AffinePoint, JacobianPoint, ProjectivePoint};
 
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 /// A NAF lookup table for storing the precomputed values
@@ -90,4 +91,60 @@ impl<const N: usize> NafLookupTable<N> {
 
         self.0[x / 2]
     }
+
+    /// Given an odd `i8` x value, returns x.P in constant time.
+    ///
+    /// **NOTE**: The provided value x **MUST** be odd and with
+    /// absolute value smaller than 2N for a `NafLookupTable` of N elements.
+    // To do so, we first compute |x|.P, and then conditionally
+    // negate the result based on the sign of x.
+    pub(crate) fn get_signed_point(&self, x: i8) -> AffinePoint {
+        debug_assert!(x & 1 == 1);
+        debug_assert!((x as i16).abs() < 2 * N as i16);
+
+        // Compute xabs = |x|
+        let xmask = x >> 7;
+        let xabs = (x + xmask) ^ xmask;
+
+        // Get an array element in constant time
+        let mut t = AffinePoint::identity();
+        for j in 0..N {
+            let c = ((xabs as u8) >> 1).ct_eq(&(j as u8));
+            t.conditional_assign(&self.0[j], c);
+        }
+        // Now t == |x| * p.
+
+        let neg_mask = Choice::from((xmask & 1) as u8);
+        t.conditional_assign(&-t, neg_mask);
+        // Now t == x * p.
+
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_get_signed_point() {
+        let mut rng = OsRng;
+        let p = ProjectivePoint::random(&mut rng);
+        let table = NafLookupTable::<8>::from(&p);
+
+        for x in (-15i8..16).step_by(2) {
+            let expected = if x < 0 {
+                -(p * Scalar::from((-x) as u8))
+            } else {
+                p * Scalar::from(x as u8)
+            };
+
+            assert_eq!(ProjectivePoint::from(table.get_signed_point(x)), expected);
+            if x > 0 {
+                assert_eq!(table.get_signed_point(x), table.get_point(x as usize));
+            }
+        }
+    }
 }
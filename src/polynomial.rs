@@ -0,0 +1,538 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides univariate polynomials with coefficients in Fp6,
+//! which can be evaluated and interpolated over multiplicative subgroups
+//! of Fp (and cosets thereof) with number-theoretic transforms over the
+//! base field, applied to each coordinate of the coefficients.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Neg, Sub};
+
+use crate::fp::{Fp, TWO_ADICITY};
+use crate::fp6::Fp6;
+
+// POLYNOMIAL
+// ================================================================================================
+
+/// A univariate polynomial with coefficients in Fp6,
+/// stored in increasing degree order.
+///
+/// The coefficients may be padded with trailing zeros, which
+/// are ignored when comparing polynomials.
+#[derive(Clone, Debug, Default)]
+pub struct Polynomial {
+    coefficients: Vec<Fp6>,
+}
+
+impl PartialEq for Polynomial {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed_coefficients() == other.trimmed_coefficients()
+    }
+}
+
+impl Eq for Polynomial {}
+
+impl Polynomial {
+    /// Creates a new polynomial from its coefficients,
+    /// given in increasing degree order.
+    pub fn new(coefficients: Vec<Fp6>) -> Self {
+        Self { coefficients }
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    /// Returns the coefficients of this polynomial,
+    /// in increasing degree order.
+    pub fn coefficients(&self) -> &[Fp6] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients of this polynomial,
+    /// without trailing zeros.
+    fn trimmed_coefficients(&self) -> &[Fp6] {
+        let len = self
+            .coefficients
+            .iter()
+            .rposition(|c| !bool::from(c.is_zero()))
+            .map_or(0, |i| i + 1);
+
+        &self.coefficients[..len]
+    }
+
+    /// Returns the degree of this polynomial.
+    /// The zero polynomial is considered of degree 0.
+    pub fn degree(&self) -> usize {
+        self.trimmed_coefficients().len().saturating_sub(1)
+    }
+
+    /// Evaluates this polynomial at a point of Fp6.
+    pub fn evaluate(&self, x: &Fp6) -> Fp6 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Fp6::zero(), |acc, c| acc * x + c)
+    }
+
+    /// Evaluates this polynomial at a point of the base field Fp.
+    pub fn evaluate_at_base(&self, x: &Fp) -> Fp6 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Fp6::zero(), |acc, c| acc.mul_by_fp(x) + c)
+    }
+
+    /// Evaluates this polynomial over the coset `offset.H` of the
+    /// multiplicative subgroup H of Fp of order `domain_size`,
+    /// generated by `Fp::get_root_of_unity(log2(domain_size))`.
+    ///
+    /// The i-th output is the evaluation at `offset.g^i`, where g is
+    /// the generator of H.
+    ///
+    /// Panics if `domain_size` is not a power of two between 1 and 2^32,
+    /// or if it is smaller than the number of coefficients.
+    pub fn evaluate_over_domain(&self, domain_size: usize, offset: Fp) -> Vec<Fp6> {
+        let log_size = log2_domain_size(domain_size);
+        assert!(
+            self.coefficients.len() <= domain_size,
+            "domain size cannot be smaller than the number of coefficients"
+        );
+
+        let mut columns = split_coordinates(&self.coefficients, domain_size);
+
+        let root = get_domain_generator(log_size);
+        for column in columns.iter_mut() {
+            distribute_powers(column, offset);
+            fft_in_place(column, root);
+        }
+
+        merge_coordinates(&columns)
+    }
+
+    /// Interpolates the unique polynomial of degree less than
+    /// `evaluations.len()` taking the given values over the coset
+    /// `offset.H` of the multiplicative subgroup H of Fp of order
+    /// `evaluations.len()`, with the same ordering as the output of
+    /// `evaluate_over_domain`.
+    ///
+    /// Panics if the number of evaluations is not a power of two
+    /// between 1 and 2^32, or if `offset` is zero.
+    pub fn interpolate_over_domain(evaluations: &[Fp6], offset: Fp) -> Self {
+        let domain_size = evaluations.len();
+        let log_size = log2_domain_size(domain_size);
+        let offset_inv = offset.invert();
+        assert!(bool::from(offset_inv.is_some()), "offset cannot be zero");
+        let offset_inv = offset_inv.unwrap();
+
+        let mut columns = split_coordinates(evaluations, domain_size);
+
+        let root_inv = get_domain_generator(log_size).invert().unwrap();
+        let size_inv = Fp::new(domain_size as u64).invert().unwrap();
+        for column in columns.iter_mut() {
+            fft_in_place(column, root_inv);
+            distribute_powers(column, offset_inv);
+            for c in column.iter_mut() {
+                *c *= size_inv;
+            }
+        }
+
+        Self {
+            coefficients: merge_coordinates(&columns),
+        }
+    }
+
+    /// Divides this polynomial by (X - z), returning the quotient
+    /// and the remainder, which equals the evaluation at z.
+    ///
+    /// This is typically used to compute DEEP quotients
+    /// (f(X) - f(z)) / (X - z) at out-of-domain points z.
+    pub fn divide_by_linear(&self, z: &Fp6) -> (Self, Fp6) {
+        if self.coefficients.is_empty() {
+            return (Self::zero(), Fp6::zero());
+        }
+
+        // Synthetic division, from the highest coefficient down
+        let mut quotient = vec![Fp6::zero(); self.coefficients.len() - 1];
+        let mut acc = Fp6::zero();
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            acc = acc * z + c;
+            if i > 0 {
+                quotient[i - 1] = acc;
+            }
+        }
+
+        (Self::new(quotient), acc)
+    }
+
+    /// Multiplies this polynomial by a scalar of Fp6.
+    pub fn scale(&self, scalar: &Fp6) -> Self {
+        Self {
+            coefficients: self.coefficients.iter().map(|c| c * scalar).collect(),
+        }
+    }
+}
+
+// OVERLOADED OPERATORS
+// ================================================================================================
+
+impl Add<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut coefficients = long.coefficients.clone();
+        for (c, s) in coefficients.iter_mut().zip(short.coefficients.iter()) {
+            *c += s;
+        }
+
+        Polynomial { coefficients }
+    }
+}
+
+impl Sub<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: &Polynomial) -> Polynomial {
+        self + &(-rhs)
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        Polynomial {
+            coefficients: self.coefficients.iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Polynomial) -> Polynomial {
+        &self + &rhs
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: Polynomial) -> Polynomial {
+        &self - &rhs
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
+
+// HELPER METHODS
+// ================================================================================================
+
+/// Returns log2(domain_size), checking that the domain is a valid
+/// multiplicative subgroup of Fp.
+fn log2_domain_size(domain_size: usize) -> u32 {
+    assert!(
+        domain_size.is_power_of_two(),
+        "domain size must be a power of two"
+    );
+    let log_size = domain_size.trailing_zeros();
+    assert!(
+        log_size <= TWO_ADICITY,
+        "domain size cannot exceed 2^{}",
+        TWO_ADICITY
+    );
+
+    log_size
+}
+
+/// Returns the generator of the multiplicative subgroup of Fp of order 2^log_size.
+fn get_domain_generator(log_size: u32) -> Fp {
+    if log_size == 0 {
+        Fp::one()
+    } else {
+        Fp::get_root_of_unity_vartime(log_size)
+    }
+}
+
+/// Splits Fp6 elements into their 6 coordinates over Fp,
+/// padding each resulting column with zeros up to `size`.
+fn split_coordinates(elements: &[Fp6], size: usize) -> [Vec<Fp>; 6] {
+    let mut columns: [Vec<Fp>; 6] = Default::default();
+    for column in columns.iter_mut() {
+        *column = vec![Fp::zero(); size];
+    }
+
+    for (i, element) in elements.iter().enumerate() {
+        let coordinates: [Fp; 6] = element.into();
+        for (column, c) in columns.iter_mut().zip(coordinates.iter()) {
+            column[i] = *c;
+        }
+    }
+
+    columns
+}
+
+/// Recombines 6 columns of Fp coordinates into Fp6 elements.
+fn merge_coordinates(columns: &[Vec<Fp>; 6]) -> Vec<Fp6> {
+    (0..columns[0].len())
+        .map(|i| {
+            Fp6::from([
+                columns[0][i],
+                columns[1][i],
+                columns[2][i],
+                columns[3][i],
+                columns[4][i],
+                columns[5][i],
+            ])
+        })
+        .collect()
+}
+
+/// Multiplies the i-th element of `values` by `factor^i`.
+fn distribute_powers(values: &mut [Fp], factor: Fp) {
+    if factor == Fp::one() {
+        return;
+    }
+
+    let mut power = Fp::one();
+    for v in values.iter_mut() {
+        *v *= power;
+        power *= factor;
+    }
+}
+
+/// Computes in place the evaluations of the polynomial with
+/// coefficients `values` at the powers of `root`, using an iterative
+/// radix-2 Cooley-Tukey number-theoretic transform.
+///
+/// `root` must be of multiplicative order `values.len()`,
+/// which must be a power of two.
+pub(crate) fn fft_in_place(values: &mut [Fp], root: Fp) {
+    let n = values.len();
+    debug_assert!(n.is_power_of_two());
+    if n == 1 {
+        return;
+    }
+
+    // Bit-reversal permutation
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // Butterflies
+    let mut half_size = 1;
+    while half_size < n {
+        let step_root = root.exp_vartime((n / (2 * half_size)) as u64);
+        for chunk in values.chunks_mut(2 * half_size) {
+            let (low, high) = chunk.split_at_mut(half_size);
+            let mut twiddle = Fp::one();
+            for (u, v) in low.iter_mut().zip(high.iter_mut()) {
+                let t = *v * twiddle;
+                *v = *u - t;
+                *u += t;
+                twiddle *= step_root;
+            }
+        }
+        half_size *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    fn random_polynomial(num_coefficients: usize) -> Polynomial {
+        let mut rng = OsRng;
+        Polynomial::new(
+            (0..num_coefficients)
+                .map(|_| Fp6::random(&mut rng))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_fft() {
+        let mut rng = OsRng;
+
+        for log_n in 0..6 {
+            let n = 1 << log_n;
+            let coefficients: Vec<Fp> = (0..n).map(|_| Fp::random(&mut rng)).collect();
+            let root = get_domain_generator(log_n);
+
+            let mut values = coefficients.clone();
+            fft_in_place(&mut values, root);
+
+            let mut x = Fp::one();
+            for v in values.iter() {
+                let expected = coefficients
+                    .iter()
+                    .rev()
+                    .fold(Fp::zero(), |acc, c| acc * x + c);
+                assert_eq!(*v, expected);
+                x *= root;
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rng = OsRng;
+        let p = random_polynomial(10);
+        let x = Fp::random(&mut rng);
+
+        let mut expected = Fp6::zero();
+        let mut power = Fp6::one();
+        for c in p.coefficients() {
+            expected += c * power;
+            power *= Fp6::from(x);
+        }
+
+        assert_eq!(p.evaluate(&Fp6::from(x)), expected);
+        assert_eq!(p.evaluate_at_base(&x), expected);
+
+        assert_eq!(Polynomial::zero().evaluate(&Fp6::one()), Fp6::zero());
+    }
+
+    #[test]
+    fn test_evaluate_over_domain() {
+        let mut rng = OsRng;
+
+        for log_n in 0..6 {
+            let n = 1 << log_n;
+            let g = get_domain_generator(log_n);
+
+            for num_coefficients in [n / 2, n] {
+                let p = random_polynomial(num_coefficients);
+
+                for offset in [Fp::one(), Fp::random(&mut rng)] {
+                    let evaluations = p.evaluate_over_domain(n, offset);
+                    assert_eq!(evaluations.len(), n);
+
+                    let mut x = offset;
+                    for e in evaluations.iter() {
+                        assert_eq!(*e, p.evaluate_at_base(&x));
+                        x *= g;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_over_domain() {
+        let mut rng = OsRng;
+
+        for log_n in 0..6 {
+            let n = 1 << log_n;
+            let p = random_polynomial(n);
+
+            for offset in [Fp::one(), Fp::random(&mut rng)] {
+                let evaluations = p.evaluate_over_domain(n, offset);
+                let q = Polynomial::interpolate_over_domain(&evaluations, offset);
+
+                assert_eq!(p, q);
+            }
+        }
+
+        // Low-degree polynomials are recovered padded with zeros
+        let p = random_polynomial(3);
+        let evaluations = p.evaluate_over_domain(8, Fp::new(7));
+        let q = Polynomial::interpolate_over_domain(&evaluations, Fp::new(7));
+        assert_eq!(q.degree(), 2);
+        assert_eq!(&q.coefficients()[..3], p.coefficients());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_evaluate_over_domain_too_small() {
+        let p = random_polynomial(5);
+        let _ = p.evaluate_over_domain(4, Fp::one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_evaluate_over_domain_not_power_of_two() {
+        let p = random_polynomial(5);
+        let _ = p.evaluate_over_domain(6, Fp::one());
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        let mut rng = OsRng;
+        let p = random_polynomial(16);
+        let z = Fp6::random(&mut rng);
+
+        let (quotient, remainder) = p.divide_by_linear(&z);
+        assert_eq!(remainder, p.evaluate(&z));
+        assert_eq!(quotient.coefficients().len(), 15);
+
+        // p(X) = q(X).(X - z) + p(z)
+        let x = Fp6::random(&mut rng);
+        assert_eq!(p.evaluate(&x), quotient.evaluate(&x) * (x - z) + remainder);
+
+        let (quotient, remainder) = Polynomial::zero().divide_by_linear(&z);
+        assert_eq!(quotient, Polynomial::zero());
+        assert_eq!(remainder, Fp6::zero());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = OsRng;
+        let p = random_polynomial(8);
+        let q = random_polynomial(5);
+        let s = Fp6::random(&mut rng);
+        let x = Fp6::random(&mut rng);
+
+        assert_eq!((&p + &q).evaluate(&x), p.evaluate(&x) + q.evaluate(&x));
+        assert_eq!((&q + &p).evaluate(&x), p.evaluate(&x) + q.evaluate(&x));
+        assert_eq!((&p - &q).evaluate(&x), p.evaluate(&x) - q.evaluate(&x));
+        assert_eq!((-&p).evaluate(&x), -p.evaluate(&x));
+        assert_eq!(p.scale(&s).evaluate(&x), p.evaluate(&x) * s);
+        assert_eq!((p.clone() - p).degree(), 0);
+    }
+
+    #[test]
+    fn test_eq_ignores_trailing_zeros() {
+        let p = random_polynomial(8);
+        let q = random_polynomial(5);
+
+        assert_eq!(&p - &p, Polynomial::zero());
+        assert_eq!(&p + &(-&p), Polynomial::zero());
+        assert_eq!(&(&p + &q) - &q, p);
+        assert_eq!(&(&q + &p) - &p, q);
+        assert_ne!(p, q);
+        assert_ne!(p, Polynomial::zero());
+
+        let mut coefficients = q.coefficients().to_vec();
+        coefficients.resize(16, Fp6::zero());
+        assert_eq!(Polynomial::new(coefficients), q);
+        assert_eq!(Polynomial::new(vec![Fp6::zero(); 4]), Polynomial::zero());
+    }
+}
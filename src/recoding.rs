@@ -0,0 +1,797 @@
+// Copyright (c) 2021-2022 Toposware, Inc.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module provides recodings of scalars into signed digit
+//! representations, as used by scalar multiplication algorithms.
+//!
+//! All recodings take as input the little-endian byte representation
+//! of a canonical `Scalar`, as output by `Scalar::to_bytes()`. Each of
+//! them comes in a constant-time flavor, and in a `_vartime` flavor
+//! whose running time, and possibly output length, depend on the scalar.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater};
+
+use crate::utils::add64_with_carry;
+
+// SIGNED RADIX-2^W
+// ================================================================================================
+
+/// Converts a `Scalar` element given as byte representation into a signed
+/// radix-2^w representation, where each resulting coefficient is in
+/// [-2^(w-1); 2^(w-1)), except the last one which is in [0; 2^(w-1)].
+///
+/// The resulting decomposition `[a_0, ..., a_(n-1)]`, with `n = ceil(256 / w)`,
+/// is such that `sum(a_j * 2^(j * w)) == a`.
+///
+/// Panics if `w` is not between 2 and 8.
+pub fn to_signed_radix(bytes: &[u8; 32], w: usize) -> Vec<i8> {
+    assert!((2..=8).contains(&w), "window width must be between 2 and 8");
+
+    let mut digits = vec![0i8; 256usize.div_ceil(w)];
+    signed_radix_into(bytes, w, &mut digits);
+
+    digits
+}
+
+/// Converts a `Scalar` element given as byte representation into a signed
+/// radix-2^w representation, where each resulting coefficient is in
+/// [-2^(w-1); 2^(w-1)), except the last one which is in (0; 2^(w-1)].
+///
+/// The resulting decomposition `[a_0, ..., a_(n-1)]` is such that
+/// `sum(a_j * 2^(j * w)) == a`, and has no trailing zero coefficients.
+/// In particular, the decomposition of zero is empty.
+///
+/// Panics if `w` is not between 2 and 8.
+///
+/// **This operation is variable time with respect to the scalar.**
+/// If the scalar is fixed, this operation is effectively constant time.
+pub fn to_signed_radix_vartime(bytes: &[u8; 32], w: usize) -> Vec<i8> {
+    assert!((2..=8).contains(&w), "window width must be between 2 and 8");
+
+    let mut digits = vec![0i8; 256usize.div_ceil(w)];
+    let len = signed_radix_into_vartime(bytes, w, &mut digits);
+    digits.truncate(len);
+
+    digits
+}
+
+/// Converts a 256-bit integer given as byte representation into a signed
+/// radix-2^w representation with 32-bit coefficients, where each resulting
+/// coefficient is in [-2^(w-1); 2^(w-1)), except the last one which is in
+/// [0; 2^(w-1)] for a canonical `Scalar`, and in [0; 2^w] in general.
+///
+/// The resulting decomposition `[a_0, ..., a_(n-1)]`, with `n = ceil(256 / w)`,
+/// is such that `sum(a_j * 2^(j * w)) == a` over the integers.
+///
+/// Panics if `w` is not between 2 and 16.
+pub fn to_signed_radix_i32(bytes: &[u8; 32], w: usize) -> Vec<i32> {
+    assert!(
+        (2..=16).contains(&w),
+        "window width must be between 2 and 16"
+    );
+
+    let limbs = bytes_to_limbs(bytes);
+    let half = 1i32 << (w - 1);
+
+    let mut digits = vec![0i32; 256usize.div_ceil(w)];
+    let last = digits.len() - 1;
+    let mut carry = 0i32;
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let value = get_window(&limbs, i * w, w) as i32 + carry;
+
+        if i == last {
+            *digit = value;
+        } else {
+            // Shift the coefficient from [0; 2^w] to [-2^(w-1); 2^(w-1))
+            carry = (value + half) >> w;
+            *digit = value - (carry << w);
+        }
+    }
+
+    digits
+}
+
+// FIXED-LENGTH SIGNED WINDOWS
+// ================================================================================================
+
+/// Converts a `Scalar` element given as byte representation into a radix-16
+/// representation, where each resulting coefficient is in [-8; 8).
+///
+/// The resulting decomposition `[a_0, ..., a_63]` is such that
+/// `sum(a_j * 2^(j * 4)) == a`.
+pub fn to_signed_window_16(bytes: &[u8; 32]) -> [i8; 64] {
+    let mut digits = [0i8; 64];
+    signed_radix_into(bytes, 4, &mut digits);
+
+    digits
+}
+
+/// Converts a `Scalar` element given as byte representation into a radix-16
+/// representation, where each resulting coefficient is in [-8; 8).
+///
+/// The resulting decomposition `[a_0, ..., a_63]` is such that
+/// `sum(a_j * 2^(j * 4)) == a`.
+///
+/// **This operation is variable time with respect to the scalar.**
+/// If the scalar is fixed, this operation is effectively constant time.
+pub fn to_signed_window_16_vartime(bytes: &[u8; 32]) -> [i8; 64] {
+    let mut digits = [0i8; 64];
+    signed_radix_into_vartime(bytes, 4, &mut digits);
+
+    digits
+}
+
+/// Converts a `Scalar` element given as byte representation into a radix-256
+/// representation, where each resulting coefficient is in [-128; 128).
+///
+/// The resulting decomposition `[a_0, ..., a_31]` is such that
+/// `sum(a_j * 2^(j * 8)) == a`.
+pub fn to_signed_window_256(bytes: &[u8; 32]) -> [i8; 32] {
+    let mut digits = [0i8; 32];
+    signed_radix_into(bytes, 8, &mut digits);
+
+    digits
+}
+
+/// Converts a `Scalar` element given as byte representation into a radix-256
+/// representation, where each resulting coefficient is in [-128; 128).
+///
+/// The resulting decomposition `[a_0, ..., a_31]` is such that
+/// `sum(a_j * 2^(j * 8)) == a`.
+///
+/// **This operation is variable time with respect to the scalar.**
+/// If the scalar is fixed, this operation is effectively constant time.
+pub fn to_signed_window_256_vartime(bytes: &[u8; 32]) -> [i8; 32] {
+    let mut digits = [0i8; 32];
+    signed_radix_into_vartime(bytes, 8, &mut digits);
+
+    digits
+}
+
+// SIGNED ODD DIGITS
+// ================================================================================================
+
+/// Converts a `Scalar` element given as byte representation into a radix-16
+/// representation, where each resulting coefficient is odd and in [-15; 15].
+///
+/// As such a representation only exists for odd integers, even scalars are
+/// incremented by one prior to the recoding, which is indicated by the returned
+/// `Choice`. The resulting decomposition `[a_0, ..., a_63]` is hence such that
+/// `sum(a_j * 2^(j * 4)) == a + 1` if `a` is even, and `a` otherwise.
+pub fn to_signed_odd_window_16(bytes: &[u8; 32]) -> ([i8; 64], Choice) {
+    let mut limbs = bytes_to_limbs(bytes);
+
+    // Make the scalar odd
+    let is_even = Choice::from(!bytes[0] & 1);
+    let mut carry = is_even.unwrap_u8() as u64;
+    for limb in limbs.iter_mut() {
+        (*limb, carry) = add64_with_carry(*limb, 0, carry);
+    }
+
+    let mut digits = [0i8; 64];
+    for digit in digits[..63].iter_mut() {
+        // The window is odd, hence the digit is odd and in [-15; 15]
+        let window = limbs[0] & 0x1f;
+        *digit = window as i8 - 16;
+
+        // Compute (k - digit) / 16, which is odd, with
+        // k - digit = (k - window) + 16
+        limbs[0] = (limbs[0] & !0x1f) | 0x10;
+        shr_limbs(&mut limbs, 4);
+    }
+    digits[63] = limbs[0] as i8;
+
+    (digits, is_even)
+}
+
+/// Converts a `Scalar` element given as byte representation into a radix-16
+/// representation, where each resulting coefficient is odd and in [-15; 15].
+///
+/// As such a representation only exists for odd integers, even scalars are
+/// incremented by one prior to the recoding, which is indicated by the returned
+/// `bool`. The resulting decomposition `[a_0, ..., a_63]` is hence such that
+/// `sum(a_j * 2^(j * 4)) == a + 1` if `a` is even, and `a` otherwise.
+///
+/// **This operation is variable time with respect to the scalar.**
+/// If the scalar is fixed, this operation is effectively constant time.
+pub fn to_signed_odd_window_16_vartime(bytes: &[u8; 32]) -> ([i8; 64], bool) {
+    let mut limbs = bytes_to_limbs(bytes);
+
+    let is_even = bytes[0] & 1 == 0;
+    if is_even {
+        let mut i = 0;
+        loop {
+            limbs[i] = limbs[i].wrapping_add(1);
+            if limbs[i] != 0 {
+                break;
+            }
+            i += 1;
+        }
+    }
+
+    let mut digits = [0i8; 64];
+    for digit in digits[..63].iter_mut() {
+        let window = (limbs[0] & 0x1f) as i8;
+        *digit = window - 16;
+
+        limbs[0] = (limbs[0] & !0x1f) | 0x10;
+        shr_limbs(&mut limbs, 4);
+    }
+    digits[63] = limbs[0] as i8;
+
+    (digits, is_even)
+}
+
+// WIDTH-W NAF
+// ================================================================================================
+
+/// Converts a `Scalar` element given as byte representation into a w-NAF
+/// representation, where each resulting coefficient is odd and in (-2^(w-1); 2^(w-1)).
+/// In addition, the leading coefficient is non-zero, and there cannot be
+/// more than one non-zero coefficient in any w consecutive set of coefficients.
+///
+/// The resulting decomposition `[a_0, ..., a_255]` is such that
+/// `sum(a_j * 2^j) == a`.
+///
+/// Panics if `w` is not between 2 and 8.
+pub fn to_wnaf(bytes: &[u8; 32], w: usize) -> [i8; 256] {
+    assert!((2..=8).contains(&w), "window width must be between 2 and 8");
+
+    let limbs = bytes_to_limbs(bytes);
+
+    let width = 1u64 << w;
+    let mut naf = [0i8; 256];
+
+    // Contrary to the variable time version, we go through every position,
+    // and keep track of the number of upcoming positions that are covered
+    // by the window of the last non-zero coefficient.
+    let mut carry = 0u64;
+    let mut skip = 0u64;
+    for (pos, digit) in naf.iter_mut().enumerate() {
+        let window = carry + get_window(&limbs, pos, w);
+
+        let is_active = skip.ct_eq(&0);
+        let is_emitting = is_active & Choice::from((window & 1) as u8);
+        let is_negative = window.ct_gt(&(width / 2 - 1));
+
+        let value = u64::conditional_select(&window, &window.wrapping_sub(width), is_negative);
+        *digit = i8::conditional_select(&0, &(value as i8), is_emitting);
+
+        carry = u64::conditional_select(&carry, &(is_negative.unwrap_u8() as u64), is_emitting);
+        skip = u64::conditional_select(
+            &(skip - (!is_active).unwrap_u8() as u64),
+            &(w as u64 - 1),
+            is_emitting,
+        );
+    }
+
+    naf
+}
+
+/// Converts a `Scalar` element given as byte representation into a w-NAF
+/// representation, where each resulting coefficient is odd and in (-2^(w-1); 2^(w-1)).
+/// In addition, the leading coefficient is non-zero, and there cannot be
+/// more than one non-zero coefficient in any w consecutive set of coefficients.
+///
+/// The resulting decomposition `[a_0, ..., a_255]` is such that
+/// `sum(a_j * 2^j) == a`.
+///
+/// Panics if `w` is not between 2 and 8.
+///
+/// **This operation is variable time with respect to the scalar.**
+/// If the scalar is fixed, this operation is effectively constant time.
+pub fn to_wnaf_vartime(bytes: &[u8; 32], w: usize) -> [i8; 256] {
+    // Taken from https://github.com/dalek-cryptography/curve25519-dalek/blob/main/src/scalar.rs
+    // from an adaptation of Algorithm 3.35 in Guide to Elliptic Curve Cryptography by
+    // Hankerson, Menezes and Vanstone.
+
+    assert!((2..=8).contains(&w), "window width must be between 2 and 8");
+
+    let limbs = bytes_to_limbs(bytes);
+
+    let width = 1 << w;
+
+    let mut naf = [0i8; 256];
+
+    let mut pos = 0;
+    let mut carry = 0;
+    while pos < 256 {
+        // Add the carry into the current window
+        let window = carry + get_window(&limbs, pos, w);
+
+        if window & 1 == 0 {
+            // If the window value is even, preserve the carry and continue.
+            // Why is the carry preserved?
+            // If carry == 0 and window & 1 == 0, then the next carry should be 0
+            // If carry == 1 and window & 1 == 0, then bit_buf & 1 == 1 so the next carry should be 1
+            pos += 1;
+            continue;
+        }
+
+        if window < width / 2 {
+            carry = 0;
+            naf[pos] = window as i8;
+        } else {
+            carry = 1;
+            naf[pos] = (window as i8).wrapping_sub(width as i8);
+        }
+
+        pos += w;
+    }
+
+    naf
+}
+
+// JOINT SPARSE FORM
+// ================================================================================================
+
+/// Converts two `Scalar` elements given as byte representations into their
+/// joint sparse form, where each resulting coefficient is in {-1, 0, 1}.
+/// Among any three consecutive columns, at least one is zero, and the joint
+/// representation has the minimal number of non-zero columns.
+///
+/// The resulting decompositions `[[a_0, ..., a_255], [b_0, ..., b_255]]` are
+/// such that `sum(a_j * 2^j) == lhs` and `sum(b_j * 2^j) == rhs`.
+pub fn to_jsf(lhs: &[u8; 32], rhs: &[u8; 32]) -> [[i8; 256]; 2] {
+    let mut k = [bytes_to_limbs(lhs), bytes_to_limbs(rhs)];
+    let mut d = [0u64; 2];
+
+    let mut lhs_digits = [0i8; 256];
+    let mut rhs_digits = [0i8; 256];
+
+    for (lhs_digit, rhs_digit) in lhs_digits.iter_mut().zip(rhs_digits.iter_mut()) {
+        // l_i mod 8, with l_i = k_i + d_i
+        let l = [((k[0][0] & 7) + d[0]) & 7, ((k[1][0] & 7) + d[1]) & 7];
+
+        let mut u = [0i8; 2];
+        for i in 0..2 {
+            let is_odd = Choice::from((l[i] & 1) as u8);
+            // l_i mods 4, which is 1 if l_i = 1 mod 4 and -1 otherwise
+            let sign = 1 - (l[i] & 2) as i8;
+            // The sign is flipped if l_i = ±3 mod 8 and l_(1-i) = 2 mod 4
+            let is_three_mod_eight = Choice::from((((l[i] >> 1) ^ (l[i] >> 2)) & 1) as u8);
+            let is_flipped = is_three_mod_eight & (l[1 - i] & 3).ct_eq(&2);
+            let sign = i8::conditional_select(&sign, &-sign, is_flipped);
+
+            u[i] = i8::conditional_select(&0, &sign, is_odd);
+        }
+
+        *lhs_digit = u[0];
+        *rhs_digit = u[1];
+
+        for i in 0..2 {
+            let is_updated = ((2 * d[i]) as i8).ct_eq(&(1 + u[i]));
+            d[i] = u64::conditional_select(&d[i], &(1 - d[i]), is_updated);
+            shr_limbs(&mut k[i], 1);
+        }
+    }
+
+    [lhs_digits, rhs_digits]
+}
+
+/// Converts two `Scalar` elements given as byte representations into their
+/// joint sparse form, where each resulting coefficient is in {-1, 0, 1}.
+/// Among any three consecutive columns, at least one is zero, and the joint
+/// representation has the minimal number of non-zero columns.
+///
+/// The resulting decompositions `[[a_0, ..., a_255], [b_0, ..., b_255]]` are
+/// such that `sum(a_j * 2^j) == lhs` and `sum(b_j * 2^j) == rhs`.
+///
+/// **This operation is variable time with respect to the scalars.**
+/// If the scalars are fixed, this operation is effectively constant time.
+pub fn to_jsf_vartime(lhs: &[u8; 32], rhs: &[u8; 32]) -> [[i8; 256]; 2] {
+    // Algorithm 3.50 in Guide to Elliptic Curve Cryptography by
+    // Hankerson, Menezes and Vanstone.
+
+    let mut k = [bytes_to_limbs(lhs), bytes_to_limbs(rhs)];
+    let mut d = [0u64; 2];
+
+    let mut jsf = [[0i8; 256]; 2];
+
+    let mut pos = 0;
+    while k[0] != [0; 5] || k[1] != [0; 5] || d != [0; 2] {
+        let l = [((k[0][0] & 7) + d[0]) & 7, ((k[1][0] & 7) + d[1]) & 7];
+
+        for i in 0..2 {
+            if l[i] & 1 == 1 {
+                let mut u = if l[i] & 3 == 1 { 1 } else { -1 };
+                if (l[i] == 3 || l[i] == 5) && l[1 - i] & 3 == 2 {
+                    u = -u;
+                }
+                jsf[i][pos] = u;
+            }
+        }
+
+        for i in 0..2 {
+            if 2 * d[i] as i8 == 1 + jsf[i][pos] {
+                d[i] = 1 - d[i];
+            }
+            shr_limbs(&mut k[i], 1);
+        }
+
+        pos += 1;
+    }
+
+    jsf
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a byte representation into 64-bit limbs, with an additional
+/// zero limb for easier window extraction.
+#[inline(always)]
+fn bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 5] {
+    let mut limbs = [0u64; 5];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(<[u8; 8]>::try_from(chunk).unwrap());
+    }
+
+    limbs
+}
+
+/// Returns the `w` bits of `limbs` starting at bit `pos`.
+#[inline(always)]
+fn get_window(limbs: &[u64; 5], pos: usize, w: usize) -> u64 {
+    let u64_idx = pos / 64;
+    let bit_idx = pos % 64;
+    let bit_buf = if bit_idx < 64 - w {
+        // This window's bits are contained in a single u64
+        limbs[u64_idx] >> bit_idx
+    } else {
+        // Combine the current u64's bits with the bits from the next u64
+        (limbs[u64_idx] >> bit_idx) | (limbs[1 + u64_idx] << (64 - bit_idx))
+    };
+
+    bit_buf & ((1 << w) - 1)
+}
+
+/// Shifts the integer represented by `limbs` by `n` bits to the right,
+/// with `n` in [1; 64).
+#[inline(always)]
+fn shr_limbs(limbs: &mut [u64; 5], n: u32) {
+    for i in 0..4 {
+        limbs[i] = (limbs[i] >> n) | (limbs[i + 1] << (64 - n));
+    }
+    limbs[4] >>= n;
+}
+
+/// Writes the signed radix-2^w decomposition of `bytes` into `digits`,
+/// which must contain `ceil(256 / w)` elements.
+pub(crate) fn signed_radix_into(bytes: &[u8; 32], w: usize, digits: &mut [i8]) {
+    debug_assert_eq!(digits.len(), 256usize.div_ceil(w));
+
+    let limbs = bytes_to_limbs(bytes);
+    let half = 1i16 << (w - 1);
+
+    let last = digits.len() - 1;
+    let mut carry = 0i16;
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let value = get_window(&limbs, i * w, w) as i16 + carry;
+
+        if i == last {
+            *digit = value as i8;
+        } else {
+            // Shift the coefficient from [0; 2^w] to [-2^(w-1); 2^(w-1))
+            carry = (value + half) >> w;
+            *digit = (value - (carry << w)) as i8;
+        }
+    }
+}
+
+/// Writes the signed radix-2^w decomposition of `bytes` into `digits`,
+/// which must contain `ceil(256 / w)` zero elements, and returns the
+/// number of coefficients up to the last non-zero one.
+pub(crate) fn signed_radix_into_vartime(bytes: &[u8; 32], w: usize, digits: &mut [i8]) -> usize {
+    debug_assert_eq!(digits.len(), 256usize.div_ceil(w));
+
+    let limbs = bytes_to_limbs(bytes);
+    let half = 1i16 << (w - 1);
+
+    // Index of the highest non-zero bit, plus one
+    let num_bits = match limbs.iter().rposition(|&limb| limb != 0) {
+        Some(i) => 64 * i + 64 - limbs[i].leading_zeros() as usize,
+        None => 0,
+    };
+
+    let last = digits.len() - 1;
+    let mut carry = 0i16;
+    let mut i = 0;
+    while i * w < num_bits || carry != 0 {
+        let value = get_window(&limbs, i * w, w) as i16 + carry;
+
+        if i == last || value < half {
+            carry = 0;
+            digits[i] = value as i8;
+        } else {
+            carry = 1;
+            digits[i] = (value - (1 << w)) as i8;
+        }
+
+        i += 1;
+    }
+
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+    use rand_core::OsRng;
+
+    fn scalar_from_digits(digits: &[i8], w: usize) -> Scalar {
+        let mut result = Scalar::zero();
+        for &digit in digits.iter().rev() {
+            for _ in 0..w {
+                result = result.double();
+            }
+            if digit < 0 {
+                result -= Scalar::from((-(digit as i64)) as u64);
+            } else {
+                result += Scalar::from(digit as u64);
+            }
+        }
+
+        result
+    }
+
+    fn test_scalars() -> Vec<Scalar> {
+        let mut rng = OsRng;
+        let mut scalars = vec![
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            Scalar::from(0xffu8),
+            Scalar::from(u64::MAX),
+        ];
+        for _ in 0..100 {
+            scalars.push(Scalar::random(&mut rng));
+        }
+
+        scalars
+    }
+
+    // SIGNED RADIX-2^W
+    // ================================================================================================
+
+    #[test]
+    fn test_to_signed_radix() {
+        for a in test_scalars() {
+            let bytes = a.to_bytes();
+
+            for w in 2..=8 {
+                let half = 1i16 << (w - 1);
+
+                let digits = to_signed_radix(&bytes, w);
+                assert_eq!(digits.len(), 256usize.div_ceil(w));
+                assert_eq!(scalar_from_digits(&digits, w), a);
+                for &digit in digits[..digits.len() - 1].iter() {
+                    assert!((-half..half).contains(&(digit as i16)));
+                }
+                assert!((0..=half).contains(&(digits[digits.len() - 1] as i16)));
+
+                let digits_vartime = to_signed_radix_vartime(&bytes, w);
+                assert_eq!(digits_vartime[..], digits[..digits_vartime.len()]);
+                assert!(digits[digits_vartime.len()..].iter().all(|&d| d == 0));
+                assert!(digits_vartime.last() != Some(&0));
+            }
+        }
+
+        assert!(to_signed_radix_vartime(&Scalar::zero().to_bytes(), 4).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_signed_radix_invalid_width() {
+        let _ = to_signed_radix(&Scalar::one().to_bytes(), 9);
+    }
+
+    #[test]
+    fn test_to_signed_radix_i32() {
+        for a in test_scalars() {
+            let bytes = a.to_bytes();
+
+            for w in 2..=16 {
+                let half = 1i32 << (w - 1);
+
+                let digits = to_signed_radix_i32(&bytes, w);
+                assert_eq!(digits.len(), 256usize.div_ceil(w));
+                for &digit in digits[..digits.len() - 1].iter() {
+                    assert!((-half..half).contains(&digit));
+                }
+                assert!((0..=half).contains(&digits[digits.len() - 1]));
+
+                let mut b = Scalar::zero();
+                for &digit in digits.iter().rev() {
+                    b *= Scalar::from(1u64 << w);
+                    if digit < 0 {
+                        b -= Scalar::from((-digit) as u64);
+                    } else {
+                        b += Scalar::from(digit as u64);
+                    }
+                }
+                assert_eq!(a, b);
+
+                if w <= 8 {
+                    let digits_i8 = to_signed_radix(&bytes, w);
+                    assert!(digits.iter().zip(digits_i8).all(|(&x, y)| x == y as i32));
+                }
+            }
+        }
+
+        // The top coefficient of a non-canonical input may reach 2^w
+        for w in [2, 4, 8, 16] {
+            let digits = to_signed_radix_i32(&[0xff; 32], w);
+            // 2^256 - 1 == -1 + 2^w * 2^(256 - w)
+            assert_eq!(digits[0], -1);
+            assert!(digits[1..digits.len() - 1].iter().all(|&d| d == 0));
+            assert_eq!(digits[digits.len() - 1], 1 << w);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_signed_radix_i32_invalid_width() {
+        let _ = to_signed_radix_i32(&Scalar::one().to_bytes(), 17);
+    }
+
+    // FIXED-LENGTH SIGNED WINDOWS
+    // ================================================================================================
+
+    #[test]
+    fn test_to_signed_window_16() {
+        for a in test_scalars() {
+            let digits = to_signed_window_16(&a.to_bytes());
+
+            assert_eq!(scalar_from_digits(&digits, 4), a);
+            assert!(digits[..63].iter().all(|&d| (-8..8).contains(&d)));
+            assert_eq!(digits, to_signed_window_16_vartime(&a.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_to_signed_window_256() {
+        for a in test_scalars() {
+            let digits = to_signed_window_256(&a.to_bytes());
+
+            assert_eq!(scalar_from_digits(&digits, 8), a);
+            assert_eq!(digits, to_signed_window_256_vartime(&a.to_bytes()));
+        }
+    }
+
+    // SIGNED ODD DIGITS
+    // ================================================================================================
+
+    #[test]
+    fn test_to_signed_odd_window_16() {
+        for a in test_scalars() {
+            let (digits, is_even) = to_signed_odd_window_16(&a.to_bytes());
+
+            assert!(digits
+                .iter()
+                .all(|&d| d & 1 == 1 && (-15..=15).contains(&d)));
+            assert_eq!(bool::from(is_even), a.to_bytes()[0] & 1 == 0,);
+            let expected = if bool::from(is_even) {
+                a + Scalar::one()
+            } else {
+                a
+            };
+            assert_eq!(scalar_from_digits(&digits, 4), expected);
+
+            assert_eq!(
+                (digits, bool::from(is_even)),
+                to_signed_odd_window_16_vartime(&a.to_bytes())
+            );
+        }
+    }
+
+    // WIDTH-W NAF
+    // ================================================================================================
+
+    #[test]
+    fn test_to_wnaf() {
+        for a in test_scalars() {
+            let bytes = a.to_bytes();
+
+            for w in 2..=8 {
+                let bound = 1i16 << (w - 1);
+
+                let digits = to_wnaf(&bytes, w);
+                assert_eq!(scalar_from_digits(&digits, 1), a);
+                assert_eq!(digits, to_wnaf_vartime(&bytes, w));
+
+                let mut last_non_zero: Option<usize> = None;
+                for (pos, &digit) in digits.iter().enumerate() {
+                    if digit != 0 {
+                        assert!(digit & 1 == 1);
+                        assert!((digit as i16).abs() < bound);
+                        if let Some(last) = last_non_zero {
+                            assert!(pos - last >= w);
+                        }
+                        last_non_zero = Some(pos);
+                    }
+                }
+            }
+        }
+    }
+
+    // JOINT SPARSE FORM
+    // ================================================================================================
+
+    #[test]
+    fn test_to_jsf() {
+        let scalars = test_scalars();
+        for (a, b) in scalars.iter().zip(scalars.iter().rev()) {
+            let [digits_a, digits_b] = to_jsf(&a.to_bytes(), &b.to_bytes());
+
+            assert_eq!(scalar_from_digits(&digits_a, 1), *a);
+            assert_eq!(scalar_from_digits(&digits_b, 1), *b);
+            assert_eq!(
+                [digits_a, digits_b],
+                to_jsf_vartime(&a.to_bytes(), &b.to_bytes())
+            );
+
+            for pos in 0..254 {
+                // Among any three consecutive columns, at least one is zero
+                assert!(
+                    (pos..pos + 3).any(|j| digits_a[j] == 0 && digits_b[j] == 0),
+                    "three consecutive non-zero columns at position {}",
+                    pos
+                );
+            }
+            for pos in 0..255 {
+                // Adjacent terms do not have opposite signs
+                assert!(digits_a[pos] * digits_a[pos + 1] != -1);
+                assert!(digits_b[pos] * digits_b[pos + 1] != -1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_jsf_carry_across_limbs() {
+        // Integer whose bits in [lo; hi) are set
+        fn ones(lo: usize, hi: usize) -> [u8; 32] {
+            let mut bytes = [0u8; 32];
+            for bit in lo..hi {
+                bytes[bit / 8] |= 1 << (bit % 8);
+            }
+            bytes
+        }
+
+        // Long runs of ones keep the carry set while the
+        // current limb is all ones, across limb boundaries.
+        let inputs = [
+            ones(0, 0),
+            ones(0, 128),
+            ones(0, 192),
+            ones(0, 250),
+            ones(60, 200),
+            ones(1, 129),
+            ones(64, 128),
+        ];
+
+        for lhs in inputs.iter() {
+            for rhs in inputs.iter() {
+                let [digits_a, digits_b] = to_jsf(lhs, rhs);
+                assert_eq!(
+                    scalar_from_digits(&digits_a, 1),
+                    Scalar::from_bytes(lhs).unwrap()
+                );
+                assert_eq!(
+                    scalar_from_digits(&digits_b, 1),
+                    Scalar::from_bytes(rhs).unwrap()
+                );
+                assert_eq!([digits_a, digits_b], to_jsf_vartime(lhs, rhs));
+            }
+        }
+    }
+}
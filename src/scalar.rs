@@ -20,8 +20,8 @@ use core::{
 };
 
 use crate::utils::{
-    add64_with_carry, mul64_with_carry, shl64_by_u32_with_carry, square_assign_multi,
-    sub64_with_carry,
+    add64_with_carry, limbs_less_than, mul64_with_carry, parse_literal, shl64_by_u32_with_carry,
+    square_assign_multi, sub64_with_carry,
 };
 
 use bitvec::{order::Lsb0, slice::BitSlice};
@@ -38,7 +38,7 @@ use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 // ================================================================================================
 
 // Field modulus = 55610362957290864006699123731285679659474893560816383126640993521607086746831
-const M: Scalar = Scalar([
+pub(crate) const M: Scalar = Scalar([
     0xd443623eaed4accf,
     0x327aa72330157722,
     0x563fbf0f990a37b5,
@@ -171,6 +171,22 @@ impl Scalar {
         (&Scalar(value)).mul(&R2)
     }
 
+    /// Creates a new field element from a decimal or hexadecimal
+    /// (`0x`-prefixed) string literal, which may contain `_` separators.
+    ///
+    /// Panics if the literal is malformed or not lower than the modulus.
+    /// When evaluated in a `const` context, as done by the [`scalar!`](crate::scalar)
+    /// macro, this turns invalid literals into compilation errors.
+    pub const fn from_literal(literal: &str) -> Self {
+        let value = parse_literal::<4>(literal);
+        assert!(
+            limbs_less_than(&value, &M.0),
+            "literal is not lower than the field modulus"
+        );
+
+        Scalar::new(value)
+    }
+
     /// Returns zero, the additive identity.
     #[inline]
     pub const fn zero() -> Self {
@@ -523,125 +539,6 @@ impl Scalar {
         d0 * R2 + d1 * R3
     }
 
-    /// Converts a `Scalar` element given as byte representation into a radix-16
-    /// representation, where each resulting coefficient is in [-8; 8).
-    ///
-    /// The resulting decomposition `[a_0, ..., a_63]` is such that
-    /// `sum(a_j * 2^(j * 4)) == a`.
-    pub(crate) fn bytes_to_radix_16(bytes: &[u8; 32]) -> [i8; 64] {
-        let mut result = [0i8; 64];
-
-        // Convert from bytes to radix-16
-        for i in 0..32 {
-            result[2 * i] = (bytes[i] & 0xf) as i8;
-            result[2 * i + 1] = ((bytes[i] >> 4) & 0xf) as i8;
-        }
-
-        // Shift every coefficients from [0; 16) to [-8; 8)
-        for i in 0..63 {
-            let carry = (result[i] + 8) >> 4;
-            result[i] -= carry << 4;
-            result[i + 1] += carry;
-        }
-
-        result
-    }
-
-    /// Converts a `Scalar` element given as byte representation into a radix-256
-    /// representation, where each resulting coefficient is in [-128; 128).
-    ///
-    /// The resulting decomposition `[a_0, ..., a_31]` is such that
-    /// `sum(a_j * 2^(j * 8)) == a`.
-    pub(crate) fn bytes_to_radix_256(bytes: &[u8; 32]) -> [i8; 32] {
-        let mut result = [0i16; 32];
-
-        // Convert to signed integers
-        for i in 0..32 {
-            result[i] = bytes[i] as i16;
-        }
-
-        // Shift every coefficients from [0; 255) to [-128; 128)
-        for i in 0..31 {
-            let carry = (result[i] + 128) >> 8;
-            result[i] -= carry << 8;
-            result[i + 1] += carry;
-        }
-
-        let mut result_i8 = [0i8; 32];
-        for i in 0..32 {
-            result_i8[i] = result[i] as i8;
-        }
-
-        result_i8
-    }
-
-    /// Converts a `Scalar` element given as byte representation into a w-NAF
-    /// representation, where each resulting coefficient is odd and in (-2^(w-1); 2^(w-1)).
-    /// In addition, the leading coefficient is non-zero, and there cannot be
-    /// more than one non-zero coefficient in any w consecutive set of coefficients.
-    ///
-    /// **This operation is variable time with respect to the scalar.**
-    /// If the scalar is fixed, this operation is effectively constant time.
-    pub(crate) fn bytes_to_wnaf_vartime(bytes: &[u8; 32], w: usize) -> [i8; 256] {
-        // Taken from https://github.com/dalek-cryptography/curve25519-dalek/blob/main/src/scalar.rs
-        // from an adaptation of Algorithm 3.35 in Guide to Elliptic Curve Cryptography by
-        // Hankerson, Menezes and Vanstone.
-
-        debug_assert!(w >= 2);
-        debug_assert!(w <= 8);
-
-        let mut naf = [0i8; 256];
-
-        let mut x_u64 = [0u64; 5];
-
-        x_u64[0] = u64::from_le_bytes(<[u8; 8]>::try_from(&bytes[0..8]).unwrap());
-        x_u64[1] = u64::from_le_bytes(<[u8; 8]>::try_from(&bytes[8..16]).unwrap());
-        x_u64[2] = u64::from_le_bytes(<[u8; 8]>::try_from(&bytes[16..24]).unwrap());
-        x_u64[3] = u64::from_le_bytes(<[u8; 8]>::try_from(&bytes[24..32]).unwrap());
-
-        let width = 1 << w;
-        let window_mask = width - 1;
-
-        let mut pos = 0;
-        let mut carry = 0;
-        while pos < 256 {
-            // Construct a buffer of bits of the scalar, starting at bit `pos`
-            let u64_idx = pos / 64;
-            let bit_idx = pos % 64;
-            let bit_buf = if bit_idx < 64 - w {
-                // This window's bits are contained in a single u64
-                x_u64[u64_idx] >> bit_idx
-            } else {
-                // Combine the current u64's bits with the bits from the next u64
-                (x_u64[u64_idx] >> bit_idx) | (x_u64[1 + u64_idx] << (64 - bit_idx))
-            };
-
-            // Add the carry into the current window
-            let window = carry + (bit_buf & window_mask);
-
-            if window & 1 == 0 {
-                // If the window value is even, preserve the carry and continue.
-                // Why is the carry preserved?
-                // If carry == 0 and window & 1 == 0, then the next carry should be 0
-                // If carry == 1 and window & 1 == 0, then bit_buf & 1 == 1 so the next carry should be 1
-                pos += 1;
-                continue;
-            }
-
-            if window < width / 2 {
-                carry = 0;
-                naf[pos] = window as i8;
-            } else {
-                carry = 1;
-                naf[pos] = (window as i8).wrapping_sub(width as i8);
-            }
-
-            pos += w;
-        }
-
-        naf
-    }
-
     /// Returns whether or not this element is strictly lexicographically
     /// larger than its negation.
     pub fn lexicographically_largest(&self) -> Choice {
@@ -1395,6 +1292,36 @@ mod tests {
         assert_eq!(element, element_normalized);
     }
 
+    #[test]
+    fn test_from_literal() {
+        assert_eq!(Scalar::from_literal("0"), Scalar::zero());
+        assert_eq!(Scalar::from_literal("1"), Scalar::one());
+        assert_eq!(Scalar::from_literal("0x2a"), Scalar::from(42u8));
+        assert_eq!(
+            Scalar::from_literal(
+                "55610362957290864006699123731285679659474893560816383126640993521607086746830"
+            ),
+            -Scalar::one()
+        );
+        assert_eq!(
+            Scalar::from_literal("0x100000000000000000000000000000000"),
+            Scalar::new([0, 0, 1, 0])
+        );
+
+        const MINUS_TWO: Scalar =
+            scalar!(0x7af2599b3b3f22d0_563fbf0f990a37b5_327aa72330157722_d443623eaed4accd);
+        assert_eq!(MINUS_TWO, -Scalar::one().double());
+        assert_eq!(scalar!("42"), Scalar::from(42u8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_literal_modulus() {
+        let _ = Scalar::from_literal(
+            "55610362957290864006699123731285679659474893560816383126640993521607086746831",
+        );
+    }
+
     // FIELD TRAIT
     // ================================================================================================
 
@@ -1462,77 +1389,6 @@ mod tests {
     // SERIALIZATION / DESERIALIZATION
     // ================================================================================================
 
-    #[test]
-    fn test_to_radix16() {
-        let mut rng = OsRng;
-
-        for _ in 0..100 {
-            let a = Scalar::random(&mut rng);
-            let digits = Scalar::bytes_to_radix_16(&a.to_bytes());
-
-            let radix = Scalar::from(16u64);
-            let mut term = Scalar::one();
-            let mut a_bis = Scalar::zero();
-            for &digit in digits.iter() {
-                if digit < 0 {
-                    a_bis += -Scalar::from((-(digit as i64)) as u64) * term;
-                } else {
-                    a_bis += Scalar::from(digit as u64) * term;
-                };
-                term *= radix;
-            }
-
-            assert_eq!(a_bis, a);
-        }
-    }
-
-    #[test]
-    fn test_to_radix_256() {
-        let mut rng = OsRng;
-
-        for _ in 0..100 {
-            let a = Scalar::random(&mut rng);
-            let digits = Scalar::bytes_to_radix_256(&a.to_bytes());
-
-            let radix = Scalar::from(256u64);
-            let mut term = Scalar::one();
-            let mut a_bis = Scalar::zero();
-            for &digit in digits.iter() {
-                if digit < 0 {
-                    a_bis += -Scalar::from((-(digit as i64)) as u64) * term;
-                } else {
-                    a_bis += Scalar::from(digit as u64) * term;
-                };
-                term *= radix;
-            }
-
-            assert_eq!(a_bis, a);
-        }
-    }
-
-    #[test]
-    fn test_to_naf() {
-        let mut rng = OsRng;
-        for _ in 0..100 {
-            let a = Scalar::random(&mut rng);
-
-            for w in [2, 3, 4, 5, 6, 7, 8] {
-                let digits = Scalar::bytes_to_wnaf_vartime(&a.to_bytes(), w);
-
-                let mut b = Scalar::zero();
-                for &digit in digits.iter().rev() {
-                    if digit < 0 {
-                        b = b.double() - Scalar::from((-digit as i64) as u64);
-                    } else {
-                        b = b.double() + Scalar::from(digit as u64);
-                    };
-                }
-
-                assert_eq!(a, b);
-            }
-        }
-    }
-
     #[test]
     fn test_to_bytes() {
         assert_eq!(
@@ -36,6 +36,93 @@ pub const fn shl64_by_u32_with_carry(a: u64, b: u32, carry: u64) -> (u64, u64) {
     (ret as u64, (ret >> 64) as u64)
 }
 
+/// Parses a decimal or hexadecimal (`0x`-prefixed) integer literal into
+/// `N` little-endian 64-bit limbs, at compile time when used in a `const`
+/// context. Surrounding double quotes and `_` separators are ignored.
+///
+/// Panics if the literal is empty, contains an invalid digit, or does not
+/// fit in `N` limbs.
+pub(crate) const fn parse_literal<const N: usize>(literal: &str) -> [u64; N] {
+    let bytes = literal.as_bytes();
+    let mut start = 0;
+    let mut end = bytes.len();
+
+    // Strip the quotes of string literals passed through `stringify!`
+    if end >= 2 && bytes[0] == b'"' && bytes[end - 1] == b'"' {
+        start += 1;
+        end -= 1;
+    }
+
+    let radix = if end - start > 2 && bytes[start] == b'0' && (bytes[start + 1] | 0x20) == b'x' {
+        start += 2;
+        16
+    } else {
+        10
+    };
+
+    let mut result = [0u64; N];
+    let mut has_digits = false;
+    let mut i = start;
+    while i < end {
+        let c = bytes[i];
+        i += 1;
+        let digit = match c {
+            b'_' => continue,
+            b'0'..=b'9' => (c - b'0') as u64,
+            b'a'..=b'f' if radix == 16 => (c - b'a' + 10) as u64,
+            b'A'..=b'F' if radix == 16 => (c - b'A' + 10) as u64,
+            _ => panic!("invalid digit in literal"),
+        };
+        has_digits = true;
+
+        // result = result * radix + digit
+        let mut carry = digit;
+        let mut j = 0;
+        while j < N {
+            (result[j], carry) = mul64_with_carry(0, result[j], radix, carry);
+            j += 1;
+        }
+        assert!(carry == 0, "literal is too large");
+    }
+    assert!(has_digits, "literal has no digits");
+
+    result
+}
+
+/// Returns whether the little-endian limbs `a` represent an integer
+/// strictly lower than the one represented by `b`.
+pub(crate) const fn limbs_less_than<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+
+    false
+}
+
+/// Minimum number of points processed by each thread in parallel
+/// multiscalar multiplications.
+#[cfg(feature = "parallel")]
+pub(crate) const MIN_PARALLEL_MSM_CHUNK_SIZE: usize = 16;
+
+/// Minimum number of points processed by each thread in parallel
+/// batch normalizations, which require one inversion per chunk.
+#[cfg(feature = "parallel")]
+pub(crate) const MIN_PARALLEL_BATCH_NORMALIZE_CHUNK_SIZE: usize = 64;
+
+/// Returns the size of the chunks to split `len` elements into, for them
+/// to be processed across all available threads, with at least `min_size`
+/// elements per chunk.
+#[cfg(feature = "parallel")]
+pub(crate) fn parallel_chunk_size(len: usize, min_size: usize) -> usize {
+    len.div_ceil(rayon::current_num_threads())
+        .max(min_size)
+        .max(1)
+}
+
 #[inline(always)]
 pub(crate) fn square_assign_multi<F: Field>(n: &mut F, num_times: usize) {
     for _ in 0..num_times {
@@ -341,4 +428,57 @@ mod tests {
             assert_eq!(e, e_copy.square().square());
         }
     }
+
+    #[test]
+    fn test_parse_literal() {
+        use crate::utils::parse_literal;
+
+        assert_eq!(parse_literal::<1>("0"), [0]);
+        assert_eq!(parse_literal::<1>("\"42\""), [42]);
+        assert_eq!(parse_literal::<1>("0x2A"), [42]);
+        assert_eq!(parse_literal::<1>("0X2a"), [42]);
+        assert_eq!(parse_literal::<1>("1_000_000"), [1000000]);
+        assert_eq!(parse_literal::<1>("18446744073709551615"), [u64::MAX]);
+        assert_eq!(parse_literal::<2>("18446744073709551616"), [0, 1]);
+        assert_eq!(parse_literal::<2>("0x1_0000000000000002"), [2, 1]);
+        assert_eq!(
+            parse_literal::<4>(
+                "55610362957290864006699123731285679659474893560816383126640993521607086746831"
+            ),
+            [
+                0xd443623eaed4accf,
+                0x327aa72330157722,
+                0x563fbf0f990a37b5,
+                0x7af2599b3b3f22d0,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_literal_too_large() {
+        let _ = crate::utils::parse_literal::<1>("18446744073709551616");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_literal_invalid_digit() {
+        let _ = crate::utils::parse_literal::<1>("12a4");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_literal_empty() {
+        let _ = crate::utils::parse_literal::<1>("0x");
+    }
+
+    #[test]
+    fn test_limbs_less_than() {
+        use crate::utils::limbs_less_than;
+
+        assert!(limbs_less_than(&[0, 1], &[0, 2]));
+        assert!(limbs_less_than(&[u64::MAX, 0], &[0, 1]));
+        assert!(!limbs_less_than(&[0, 1], &[0, 1]));
+        assert!(!limbs_less_than(&[1, 1], &[0, 1]));
+    }
 }